@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate criterion;
+extern crate futures;
+extern crate futures_router_sink;
+
+use criterion::{black_box, Criterion, Throughput};
+use futures::Sink;
+use futures_router_sink::{Route, RouterSink};
+
+/// The number of items routed per iteration
+const ITEMS: u64 = 1_000_000;
+
+/// Route `ITEMS` items alternately to both sinks of a `Vec`-backed router
+fn route_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("route_vec");
+    group.throughput(Throughput::Elements(ITEMS));
+
+    group.bench_function("router", |b| {
+        b.iter(|| {
+            let left = Vec::with_capacity(ITEMS as usize);
+            let right = Vec::with_capacity(ITEMS as usize);
+            let mut router = RouterSink::new(left, right);
+            for i in 0..ITEMS {
+                let item = if i & 1 == 0 { Route::Left(i) } else { Route::Right(i) };
+                router.start_send(black_box(item)).unwrap();
+            }
+            router.poll_complete().unwrap();
+            router
+        })
+    });
+
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            let mut left = Vec::with_capacity(ITEMS as usize);
+            let mut right = Vec::with_capacity(ITEMS as usize);
+            for i in 0..ITEMS {
+                let sink = if i & 1 == 0 { &mut left } else { &mut right };
+                sink.start_send(black_box(i)).unwrap();
+            }
+            (left, right)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, route_vec);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use error::RouterSinkError;
+use futures::{Async, AsyncSink, Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+
+/// A router holding back a single routed item its sink was not ready to
+/// accept, for adapters that turn their own items into routed items
+///
+/// Once an adapter has produced a routed item, the item can't be turned
+/// back into the adapter's item. It is kept here instead and sent before
+/// any other item, so the adapter never produces a routed item twice.
+pub(crate) struct BufferedRouter<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the routed items are sent to
+    router: RouterSink<A, B>,
+    /// A routed item that has not been accepted yet
+    buffered: Option<Route<A::SinkItem, B::SinkItem>>,
+}
+
+impl<A, B> BufferedRouter<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Create a new BufferedRouter without a buffered item
+    pub(crate) fn new(router: RouterSink<A, B>) -> BufferedRouter<A, B> {
+        BufferedRouter {
+            router,
+            buffered: None,
+        }
+    }
+
+    /// Access the inner router
+    pub(crate) fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    pub(crate) fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+
+    /// Try to send the buffered item, if any, putting it back if the sink
+    /// for its route is still not ready
+    fn poll_buffered(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        if let Some(item) = self.buffered.take() {
+            if let AsyncSink::NotReady(item) = self.router.start_send(item)? {
+                self.buffered = Some(item);
+                return Ok(Async::NotReady);
+            }
+        }
+        Ok(Async::Ready(()))
+    }
+
+    /// Send an item, turned into a routed item by the given closure
+    ///
+    /// The closure is only called once the buffered item has been
+    /// accepted. If it returns `None`, the item is accepted and dropped.
+    /// If the chosen sink is not ready to accept the routed item, it is
+    /// buffered and the item is reported as accepted.
+    pub(crate) fn start_send_with<T, F>(&mut self,
+                                        item: T,
+                                        f: F)
+                                        -> StartSend<T, RouterSinkError<A::SinkError, B::SinkError>>
+        where F: FnOnce(T) -> Result<Option<Route<A::SinkItem, B::SinkItem>>,
+                                     RouterSinkError<A::SinkError, B::SinkError>>
+    {
+        if self.poll_buffered()?.is_not_ready() {
+            return Ok(AsyncSink::NotReady(item));
+        }
+
+        if let Some(route) = f(item)? {
+            if let AsyncSink::NotReady(route) = self.router.start_send(route)? {
+                self.buffered = Some(route);
+            }
+        }
+        Ok(AsyncSink::Ready)
+    }
+
+    /// Send the buffered item, if any, then flush both sinks
+    pub(crate) fn poll_complete(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        try_ready!(self.poll_buffered());
+        self.router.poll_complete()
+    }
+
+    /// Send the buffered item, if any, then close both sinks
+    pub(crate) fn close(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        try_ready!(self.poll_buffered());
+        self.router.close()
+    }
+}
@@ -0,0 +1,144 @@
+use buffered::BufferedRouter;
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe, UnwindSafe};
+
+/// A sink routing items with a closure, catching any panic of the closure
+/// and surfacing it as `RouterSinkError::Panic` instead of unwinding
+/// through the executor
+///
+/// Catching a panic comes with tradeoffs:
+///
+/// - The item the closure panicked on is lost.
+/// - The closure is called again after a caught panic. Requiring it to
+///   be `UnwindSafe` only keeps it from capturing shared references to
+///   state with interior mutability. State the closure owns itself is
+///   still mutated through `&mut`, so a closure that panics halfway
+///   through updating its own state is called again with that state left
+///   inconsistent.
+/// - The panic hook still runs, so the panic is reported as usual.
+/// - Nothing is caught if panics abort, e.g. with `panic = "abort"`.
+///
+/// An item is only handed back with `NotReady` before the closure ran on
+/// it. Once routed, an item its sink is not ready for is held back, so a
+/// panicking closure never sees the same item twice.
+pub struct CatchUnwindRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the routed items are sent to
+    router: BufferedRouter<A, B>,
+    /// Decides which route an item has to take
+    route_fn: F,
+    /// The item type accepted by the router
+    item: PhantomData<fn(T)>,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new CatchUnwindRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let left = Vec::<u32>::new();
+    /// let right = Vec::<u32>::new();
+    ///
+    /// let router = RouterSink::with_catch_unwind(left, right, |x: u32| if x % 2 == 0 {
+    ///     Route::Left(x)
+    /// } else {
+    ///     Route::Right(x)
+    /// });
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is routed `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is routed `Right`
+    /// - `route_fn`: Decides which route an item has to take
+    pub fn with_catch_unwind<F, T>(left_sink: A,
+                                   right_sink: B,
+                                   route_fn: F)
+                                   -> CatchUnwindRouterSink<A, B, F, T>
+        where A: Sink,
+              B: Sink,
+              F: FnMut(T) -> Route<A::SinkItem, B::SinkItem> + UnwindSafe,
+              T: UnwindSafe
+    {
+        CatchUnwindRouterSink {
+            router: BufferedRouter::new(RouterSink::new(left_sink, right_sink)),
+            route_fn,
+            item: PhantomData,
+        }
+    }
+}
+
+/// Extract the message of a caught panic
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(x) => *x,
+        Err(payload) => {
+            match payload.downcast::<&'static str>() {
+                Ok(x) => x.to_string(),
+                Err(_) => "Box<Any>".to_string(),
+            }
+        }
+    }
+}
+
+impl<A, B, F, T> CatchUnwindRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.get_ref()
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.get_mut()
+    }
+}
+
+impl<A, B, F, T> Sink for CatchUnwindRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink,
+          F: FnMut(T) -> Route<A::SinkItem, B::SinkItem> + UnwindSafe,
+          T: UnwindSafe
+{
+    type SinkItem = T;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let route_fn = &mut self.route_fn;
+        self.router.start_send_with(item, |item| {
+            // `&mut F` is never `UnwindSafe`, so this asserts that the
+            // closure's own state may be left inconsistent by a panic, as
+            // documented
+            panic::catch_unwind(AssertUnwindSafe(|| route_fn(item)))
+                .map(Some)
+                .map_err(|x| RouterSinkError::Panic(panic_message(x)))
+        })
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
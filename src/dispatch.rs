@@ -0,0 +1,201 @@
+use error::RouterSinkError;
+use futures::{sink, Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+
+/// An item whose route is determined by its type
+///
+/// Implemented for the item types of both sinks of a router, this lets a
+/// `DispatchSink` accept bare items of either type. The `route_dispatch!`
+/// macro implements it for a pair of distinct types.
+///
+/// Due to the orphan rule, an implementation outside of this crate must
+/// involve at least one type local to the implementing crate, e.g.
+/// `RouteDispatch<u64, String>` cannot be implemented for `u64` downstream,
+/// but `RouteDispatch<u64, MyItem>` can. Wrapping foreign item types in
+/// local newtypes lifts this limitation.
+pub trait RouteDispatch<L, R> {
+    /// Tag the item with the route for its type
+    ///
+    /// # Return value
+    ///
+    /// The item tagged with its route
+    fn dispatch(self) -> Route<L, R>;
+}
+
+impl<L, R> RouteDispatch<L, R> for Route<L, R> {
+    fn dispatch(self) -> Route<L, R> {
+        self
+    }
+}
+
+/// Implement `RouteDispatch` for two distinct types, routing the first one
+/// to the left and the second one to the right
+///
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate futures_router_sink;
+///
+/// use futures_router_sink::{Route, RouteDispatch};
+///
+/// struct LogLine(String);
+/// struct Metric(u64);
+///
+/// route_dispatch!(LogLine, Metric);
+///
+/// # fn main() {
+/// match Metric(42).dispatch() {
+///     Route::Right(Metric(42)) => {}
+///     _ => unreachable!(),
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! route_dispatch {
+    ($left:ty, $right:ty) => {
+        impl $crate::RouteDispatch<$left, $right> for $left {
+            fn dispatch(self) -> $crate::Route<$left, $right> {
+                $crate::Route::Left(self)
+            }
+        }
+
+        impl $crate::RouteDispatch<$left, $right> for $right {
+            fn dispatch(self) -> $crate::Route<$left, $right> {
+                $crate::Route::Right(self)
+            }
+        }
+    };
+}
+
+/// A router accepting bare items of either sink's item type, routed by
+/// their type
+///
+/// Items are tagged through `RouteDispatch` when sent with `start_dispatch`
+/// or `send`. As a `Sink` it still accepts tagged items, so an item
+/// handed back with `NotReady` can simply be sent again as is.
+pub struct DispatchSink<A, B> {
+    /// The router the dispatched items are sent to
+    router: RouterSink<A, B>,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Adapt the router to accept bare items, routed by their type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate futures_router_sink;
+    /// # extern crate futures;
+    /// # fn main() {
+    /// use futures::Future;
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// # struct LogLine(String);
+    /// # struct Metric(u64);
+    /// # route_dispatch!(LogLine, Metric);
+    /// let router = RouterSink::new(Vec::<LogLine>::new(), Vec::<Metric>::new()).into_dispatch();
+    ///
+    /// let router = router.send(Metric(23)).wait().unwrap();
+    /// let router = router.send(LogLine("hello".to_string())).wait().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The router accepting bare items
+    pub fn into_dispatch(self) -> DispatchSink<A, B> {
+        DispatchSink { router: self }
+    }
+}
+
+impl<A, B> DispatchSink<A, B> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+
+    /// Consume the dispatch sink, returning the inner router
+    ///
+    /// # Return value
+    ///
+    /// The inner router
+    pub fn into_inner(self) -> RouterSink<A, B> {
+        self.router
+    }
+}
+
+impl<A, B> DispatchSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Start sending an item to the sink for its type
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to send
+    ///
+    /// # Return value
+    ///
+    /// The result of `start_send` for the tagged item
+    pub fn start_dispatch<T>(&mut self,
+                             item: T)
+                             -> StartSend<Route<A::SinkItem, B::SinkItem>, <Self as Sink>::SinkError>
+        where T: RouteDispatch<A::SinkItem, B::SinkItem>
+    {
+        self.router.start_send(item.dispatch())
+    }
+
+    /// Send an item to the sink for its type
+    ///
+    /// This shadows `Sink::send`, accepting bare items in addition to
+    /// tagged ones.
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to send
+    ///
+    /// # Return value
+    ///
+    /// A future resolving to the dispatch sink after the item has been
+    /// flushed
+    pub fn send<T>(self, item: T) -> sink::Send<Self>
+        where T: RouteDispatch<A::SinkItem, B::SinkItem>
+    {
+        Sink::send(self, item.dispatch())
+    }
+}
+
+impl<A, B> Sink for DispatchSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.router.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
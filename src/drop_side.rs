@@ -0,0 +1,54 @@
+use null::NullSink;
+use router_sink::RouterSink;
+
+impl<A, R> RouterSink<A, NullSink<R>> {
+    /// Create a new RouterSink delivering left routed items to the given
+    /// sink and silently discarding right routed items of type `R`
+    ///
+    /// This is the same router as `left_only` creates: right routed items
+    /// are accepted by a `NullSink`, which never fails, so errors are
+    /// `RouterSinkError<A::SinkError, ()>` like those of any other router.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{NullSink, RouterSink};
+    ///
+    /// let left = Vec::<usize>::new();
+    ///
+    /// let router: RouterSink<_, NullSink<String>> = RouterSink::new_drop_right(left);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    pub fn new_drop_right(left_sink: A) -> RouterSink<A, NullSink<R>> {
+        RouterSink::left_only(left_sink)
+    }
+}
+
+impl<L, B> RouterSink<NullSink<L>, B> {
+    /// Create a new RouterSink delivering right routed items to the given
+    /// sink and silently discarding left routed items of type `L`
+    ///
+    /// This is the same router as `right_only` creates: left routed items
+    /// are accepted by a `NullSink`, which never fails, so errors are
+    /// `RouterSinkError<(), B::SinkError>` like those of any other router.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{NullSink, RouterSink};
+    ///
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router: RouterSink<NullSink<String>, _> = RouterSink::new_drop_left(right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    pub fn new_drop_left(right_sink: B) -> RouterSink<NullSink<L>, B> {
+        RouterSink::right_only(right_sink)
+    }
+}
@@ -0,0 +1,149 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::any::Any;
+use std::fmt::{Debug, Error, Formatter};
+
+/// A routed item with both of its possible types erased
+pub type DynRoute = Route<Box<dyn Any>, Box<dyn Any>>;
+
+/// An error of a router with both of its possible types erased
+pub enum DynRouterError {
+    /// The router failed, with the errors of both sinks erased
+    Router(RouterSinkError<Box<dyn Any>, Box<dyn Any>>),
+    /// An item did not have the item type of the sink for its route,
+    /// holding the rejected item
+    TypeMismatch(DynRoute),
+}
+
+impl Debug for DynRouterError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            DynRouterError::Router(ref x) => write!(f, "Router({:?})", x),
+            DynRouterError::TypeMismatch(ref x) => write!(f, "TypeMismatch({:?})", x.side()),
+        }
+    }
+}
+
+/// An object safe router over type erased items and errors
+///
+/// This allows storing routers over different sinks and item types behind
+/// a common trait object, e.g. in a `Vec<Box<dyn DynRouter>>`. The erasure
+/// is not free: every item and every error is boxed and downcast again,
+/// costing one allocation per item and a dynamic dispatch per call.
+///
+/// An item that does not have the item type of the sink for its route is
+/// rejected with `DynRouterError::TypeMismatch`, holding the item itself.
+pub trait DynRouter {
+    /// Start sending a type erased item, see `Sink::start_send`
+    fn start_send(&mut self, item: DynRoute) -> StartSend<DynRoute, DynRouterError>;
+
+    /// Flush both inner sinks, see `Sink::poll_complete`
+    fn poll_complete(&mut self) -> Poll<(), DynRouterError>;
+
+    /// Close both inner sinks, see `Sink::close`
+    fn close(&mut self) -> Poll<(), DynRouterError>;
+}
+
+/// Adapter erasing the item and error types of a RouterSink
+struct DynRouterSink<A, B> {
+    /// The router the erased items are sent to
+    router: RouterSink<A, B>,
+}
+
+/// Box both possible types of a route
+fn erase<L, R>(route: Route<L, R>) -> DynRoute
+    where L: 'static,
+          R: 'static
+{
+    match route {
+        Route::Left(x) => Route::Left(Box::new(x)),
+        Route::Right(x) => Route::Right(Box::new(x)),
+    }
+}
+
+/// Box both possible types of an error
+fn erase_error<L, R>(error: RouterSinkError<L, R>) -> DynRouterError
+    where L: 'static,
+          R: 'static
+{
+    DynRouterError::Router(match error {
+        RouterSinkError::Left(x) => RouterSinkError::Left(Box::new(x)),
+        RouterSinkError::Right(x) => RouterSinkError::Right(Box::new(x)),
+        RouterSinkError::Panic(x) => RouterSinkError::Panic(x),
+        RouterSinkError::FlushTimeout(x) => RouterSinkError::FlushTimeout(x),
+        RouterSinkError::SideSealed(x) => RouterSinkError::SideSealed(x),
+    })
+}
+
+impl<A, B> DynRouter for DynRouterSink<A, B>
+    where A: Sink,
+          B: Sink,
+          A::SinkItem: 'static,
+          B::SinkItem: 'static,
+          A::SinkError: 'static,
+          B::SinkError: 'static
+{
+    fn start_send(&mut self, item: DynRoute) -> StartSend<DynRoute, DynRouterError> {
+        let item = match item {
+            Route::Left(x) => {
+                match x.downcast() {
+                    Ok(x) => Route::Left(*x),
+                    Err(x) => return Err(DynRouterError::TypeMismatch(Route::Left(x))),
+                }
+            }
+            Route::Right(x) => {
+                match x.downcast() {
+                    Ok(x) => Route::Right(*x),
+                    Err(x) => return Err(DynRouterError::TypeMismatch(Route::Right(x))),
+                }
+            }
+        };
+
+        self.router
+            .start_send(item)
+            .map(|x| x.map(erase))
+            .map_err(erase_error)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), DynRouterError> {
+        self.router.poll_complete().map_err(erase_error)
+    }
+
+    fn close(&mut self) -> Poll<(), DynRouterError> {
+        self.router.close().map_err(erase_error)
+    }
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink + 'static,
+          B: Sink + 'static,
+          A::SinkItem: 'static,
+          B::SinkItem: 'static,
+          A::SinkError: 'static,
+          B::SinkError: 'static
+{
+    /// Erase the sink, item and error types of the router
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::any::Any;
+    /// use futures_router_sink::{DynRouter, Route, RouterSink};
+    ///
+    /// let numbers = RouterSink::new(Vec::<u32>::new(), Vec::<u64>::new());
+    /// let strings = RouterSink::new(Vec::<String>::new(), Vec::<&str>::new());
+    ///
+    /// let mut routers: Vec<Box<dyn DynRouter>> = vec![numbers.into_dyn(), strings.into_dyn()];
+    ///
+    /// let item: Box<dyn Any> = Box::new(23u32);
+    /// assert!(routers[0].start_send(Route::Left(item)).is_ok());
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The router as a boxed `DynRouter` trait object
+    pub fn into_dyn(self) -> Box<dyn DynRouter> {
+        Box::new(DynRouterSink { router: self })
+    }
+}
@@ -0,0 +1,103 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use futures::future::Either;
+use router_sink::{Route, RouterSink};
+
+/// A sink routing `Either` items to one of two sinks, `Either::A` items
+/// to the left and `Either::B` items to the right
+///
+/// This is the left side of a router created with
+/// `RouterSink::share_right`. Items handed back as `NotReady` keep the
+/// variant they were sent as.
+pub struct EitherRouterSink<A, B> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a router over two left sinks sharing a single right sink
+    ///
+    /// The items of the router have the shape `Route<Either<LA, LB>, RI>`:
+    /// `Route::Left(Either::A(x))` goes to `left_a`,
+    /// `Route::Left(Either::B(x))` goes to `left_b` and `Route::Right(x)`
+    /// goes to the shared `right` sink. Errors of the left sinks are
+    /// nested in `RouterSinkError::Left`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Future, Sink};
+    /// use futures::future::Either;
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let left_a = Vec::<usize>::new();
+    /// let left_b = Vec::<String>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::share_right(left_a, left_b, right);
+    /// let router = router.send(Route::Left(Either::B("b".to_string()))).wait().unwrap();
+    /// assert_eq!(router.left().get_ref().right(), &vec!["b".to_string()]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_a`: The sink chosen by the router if an item is tagged as `Left(Either::A)`
+    /// - `left_b`: The sink chosen by the router if an item is tagged as `Left(Either::B)`
+    /// - `right`: The sink chosen by the router if an item is tagged as `Right`
+    pub fn share_right<C>(left_a: A, left_b: B, right: C) -> RouterSink<EitherRouterSink<A, B>, C> {
+        let left = EitherRouterSink { router: RouterSink::new(left_a, left_b) };
+        RouterSink::new(left, right)
+    }
+}
+
+impl<A, B> EitherRouterSink<A, B> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B> Sink for EitherRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = Either<A::SinkItem, B::SinkItem>;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let route = match item {
+            Either::A(x) => Route::Left(x),
+            Either::B(x) => Route::Right(x),
+        };
+        let result = self.router.start_send(route)?;
+        Ok(result.map(|x| match x {
+            Route::Left(x) => Either::A(x),
+            Route::Right(x) => Either::B(x),
+        }))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -1,3 +1,5 @@
+use router_sink::Side;
+use std::error;
 use std::fmt::{Debug, Display, Error, Formatter};
 
 /// Encapsulate errors from both Sinks
@@ -6,6 +8,52 @@ pub enum RouterSinkError<A, B> {
     Left(A),
     /// An error occured in the right route sink
     Right(B),
+    /// The routing closure panicked, holding the panic message
+    Panic(String),
+    /// Flushing the sink for the given side did not complete in time
+    FlushTimeout(Side),
+    /// An item was routed to the sink for the given side after it was sealed
+    SideSealed(Side),
+}
+
+impl<A, B> RouterSinkError<A, B> {
+    /// Convert the error of either sink into a common error type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSinkError;
+    ///
+    /// let error = RouterSinkError::<u8, u16>::Left(23);
+    /// assert_eq!(error.into_unified::<u32>().ok(), Some(23));
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The converted error of the failed sink, or the error itself if it
+    /// was raised by the router rather than one of the sinks
+    pub fn into_unified<E>(self) -> Result<E, RouterSinkError<A, B>>
+        where A: Into<E>,
+              B: Into<E>
+    {
+        match self {
+            RouterSinkError::Left(x) => Ok(x.into()),
+            RouterSinkError::Right(x) => Ok(x.into()),
+            x => Err(x),
+        }
+    }
+}
+
+impl<A, B> From<RouterSinkError<A, B>> for (Option<A>, Option<B>) {
+    /// Split the error into the errors of the left and right sinks, both
+    /// of which are `None` if the error was raised by the router itself
+    fn from(error: RouterSinkError<A, B>) -> (Option<A>, Option<B>) {
+        match error {
+            RouterSinkError::Left(x) => (Some(x), None),
+            RouterSinkError::Right(x) => (None, Some(x)),
+            _ => (None, None),
+        }
+    }
 }
 
 impl<A, B> Display for RouterSinkError<A, B>
@@ -13,9 +61,16 @@ impl<A, B> Display for RouterSinkError<A, B>
           B: Display
 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        match self {
-            &RouterSinkError::Left(ref x) => x.fmt(f),
-            &RouterSinkError::Right(ref x) => x.fmt(f),
+        match *self {
+            RouterSinkError::Left(ref x) => x.fmt(f),
+            RouterSinkError::Right(ref x) => x.fmt(f),
+            RouterSinkError::Panic(ref x) => write!(f, "routing closure panicked: {}", x),
+            RouterSinkError::FlushTimeout(Side::Left) => write!(f, "flushing the left sink timed out"),
+            RouterSinkError::FlushTimeout(Side::Right) => {
+                write!(f, "flushing the right sink timed out")
+            }
+            RouterSinkError::SideSealed(Side::Left) => write!(f, "the left sink is sealed"),
+            RouterSinkError::SideSealed(Side::Right) => write!(f, "the right sink is sealed"),
         }
     }
 }
@@ -25,9 +80,27 @@ impl<A, B> Debug for RouterSinkError<A, B>
           B: Debug
 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        match self {
-            &RouterSinkError::Left(ref x) => x.fmt(f),
-            &RouterSinkError::Right(ref x) => x.fmt(f),
+        match *self {
+            RouterSinkError::Left(ref x) => x.fmt(f),
+            RouterSinkError::Right(ref x) => x.fmt(f),
+            RouterSinkError::Panic(ref x) => write!(f, "Panic({:?})", x),
+            RouterSinkError::FlushTimeout(x) => write!(f, "FlushTimeout({:?})", x),
+            RouterSinkError::SideSealed(x) => write!(f, "SideSealed({:?})", x),
+        }
+    }
+}
+
+impl<A, B> error::Error for RouterSinkError<A, B>
+    where A: error::Error + 'static,
+          B: error::Error + 'static
+{
+    /// The source of the failed sink's error, as the error itself is
+    /// displayed as the sink's error already
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            RouterSinkError::Left(ref x) => x.source(),
+            RouterSinkError::Right(ref x) => x.source(),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,190 @@
+use error::RouterSinkError;
+use futures::{Async, AsyncSink, Poll, Sink, StartSend};
+use router_sink::RouterSink;
+use std::collections::VecDeque;
+
+/// A sink routing items to one of two sinks, sending the errors of the
+/// inner sinks to an error sink instead of failing
+///
+/// An error of an inner sink is sent to the error sink and otherwise
+/// treated as if the operation had succeeded, so a stream forwarded into
+/// the router survives a failing side. An item whose `start_send` failed
+/// is lost. Errors of the error sink itself are propagated.
+pub struct ErrorSinkRouterSink<A, B, E>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// The sink the errors of the inner sinks are sent to
+    err_sink: E,
+    /// Errors not yet accepted by the error sink
+    pending: VecDeque<RouterSinkError<A::SinkError, B::SinkError>>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Create a new ErrorSinkRouterSink for the two given sinks, sending
+    /// their errors to the given error sink
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{RouterSink, RouterSinkError};
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    /// let errors = Vec::<RouterSinkError<(), ()>>::new();
+    ///
+    /// let router = RouterSink::with_error_sink(left, right, errors);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    /// - `err_sink`: The sink the errors of the inner sinks are sent to
+    pub fn with_error_sink<E>(left_sink: A, right_sink: B, err_sink: E) -> ErrorSinkRouterSink<A, B, E>
+        where E: Sink<SinkItem = RouterSinkError<A::SinkError, B::SinkError>>
+    {
+        ErrorSinkRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            err_sink,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<A, B, E> ErrorSinkRouterSink<A, B, E>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+
+    /// Access the sink the errors are sent to
+    ///
+    /// # Return value
+    ///
+    /// A reference to the error sink
+    pub fn err_sink(&self) -> &E {
+        &self.err_sink
+    }
+
+    /// Mutable access the sink the errors are sent to
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the error sink
+    pub fn err_sink_mut(&mut self) -> &mut E {
+        &mut self.err_sink
+    }
+}
+
+impl<A, B, E> ErrorSinkRouterSink<A, B, E>
+    where A: Sink,
+          B: Sink,
+          E: Sink<SinkItem = RouterSinkError<A::SinkError, B::SinkError>>
+{
+    /// Send the pending errors to the error sink
+    ///
+    /// # Return value
+    ///
+    /// `Ready` once the error sink accepted all pending errors
+    fn send_pending(&mut self) -> Poll<(), E::SinkError> {
+        while let Some(error) = self.pending.pop_front() {
+            if let AsyncSink::NotReady(error) = self.err_sink.start_send(error)? {
+                self.pending.push_front(error);
+                return Ok(Async::NotReady);
+            }
+        }
+        Ok(Async::Ready(()))
+    }
+
+    /// Queue the errors of the given results for the error sink
+    ///
+    /// # Return value
+    ///
+    /// Whether both results are ready or failed
+    fn divert(&mut self,
+              left: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>,
+              right: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>)
+              -> bool {
+        let mut ready = true;
+        for result in [left, right] {
+            match result {
+                Ok(Async::Ready(())) => {}
+                Ok(Async::NotReady) => ready = false,
+                Err(e) => self.pending.push_back(e),
+            }
+        }
+        ready
+    }
+}
+
+impl<A, B, E> Sink for ErrorSinkRouterSink<A, B, E>
+    where A: Sink,
+          B: Sink,
+          E: Sink<SinkItem = RouterSinkError<A::SinkError, B::SinkError>>
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = E::SinkError;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        if self.send_pending()?.is_not_ready() {
+            return Ok(AsyncSink::NotReady(item));
+        }
+
+        match self.router.start_send(item) {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                self.pending.push_back(e);
+                self.send_pending()?;
+                Ok(AsyncSink::Ready)
+            }
+        }
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        let (left, right) = self.router.poll_complete_each();
+        let ready = self.divert(left, right);
+
+        try_ready!(self.send_pending());
+        try_ready!(self.err_sink.poll_complete());
+        if ready {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        let (left, right) = self.router.close_each();
+        let ready = self.divert(left, right);
+
+        try_ready!(self.send_pending());
+        if ready {
+            self.err_sink.close()
+        } else {
+            try_ready!(self.err_sink.poll_complete());
+            Ok(Async::NotReady)
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use buffered::BufferedRouter;
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::marker::PhantomData;
+
+/// Decision to route an item to one of the two sinks, or to drop it
+pub enum RouteDecision<L, R> {
+    /// Route the item left
+    Left(L),
+    /// Route the item right
+    Right(R),
+    /// Drop the item without sending it anywhere
+    Skip,
+}
+
+/// A sink filtering and routing items with a closure in a single pass
+///
+/// Items the closure decides to skip are accepted and dropped without
+/// touching either sink. An item the closure decided to route is held
+/// back if its sink is not ready, and sent before the next item is
+/// filtered, so no item is filtered twice.
+pub struct FilterRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the routed items are sent to
+    router: BufferedRouter<A, B>,
+    /// Decides which route an item has to take, if any
+    f: F,
+    /// The item type accepted by the router
+    item: PhantomData<fn(T)>,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new FilterRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{RouteDecision, RouterSink};
+    ///
+    /// let even = Vec::<u32>::new();
+    /// let odd = Vec::<u32>::new();
+    ///
+    /// let router = RouterSink::filter_route(even, odd, |x: u32| if x == 0 {
+    ///     RouteDecision::Skip
+    /// } else if x % 2 == 0 {
+    ///     RouteDecision::Left(x)
+    /// } else {
+    ///     RouteDecision::Right(x)
+    /// });
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is routed `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is routed `Right`
+    /// - `f`: Decides which route an item has to take, if any
+    pub fn filter_route<F, T>(left_sink: A, right_sink: B, f: F) -> FilterRouterSink<A, B, F, T>
+        where A: Sink,
+              B: Sink,
+              F: FnMut(T) -> RouteDecision<A::SinkItem, B::SinkItem>
+    {
+        FilterRouterSink {
+            router: BufferedRouter::new(RouterSink::new(left_sink, right_sink)),
+            f,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<A, B, F, T> FilterRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.get_ref()
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.get_mut()
+    }
+}
+
+impl<A, B, F, T> Sink for FilterRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink,
+          F: FnMut(T) -> RouteDecision<A::SinkItem, B::SinkItem>
+{
+    type SinkItem = T;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let f = &mut self.f;
+        self.router.start_send_with(item, |item| {
+            Ok(match f(item) {
+                RouteDecision::Left(x) => Some(Route::Left(x)),
+                RouteDecision::Right(x) => Some(Route::Right(x)),
+                RouteDecision::Skip => None,
+            })
+        })
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,181 @@
+use error::RouterSinkError;
+use futures::{future, Async, Poll, Sink, StartSend};
+use futures::executor::{self, Notify};
+use router_sink::RouterSink;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A router closing its sinks when dropped, so items not flushed yet are
+/// not silently lost
+///
+/// Dropping the guard closes both sinks as far as possible without
+/// blocking. As long as closing the sinks returns `NotReady` but they
+/// notify the task right away, i.e. they are still making progress, they
+/// are closed again. Once they are waiting on something else, or fail, a
+/// warning is logged with the `log` crate's `warn!` and their remaining
+/// items are lost. A sink that keeps notifying without ever finishing
+/// keeps the drop busy, which `set_max_drop_polls` can bound. As this
+/// can't await the sinks, a guard used in an asynchronous context should
+/// still be closed explicitly, after which dropping it does nothing.
+pub struct RouterSinkGuard<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// The guarded router, taken when the guard is disarmed
+    router: Option<RouterSink<A, B>>,
+    /// Whether the router has been closed already
+    closed: bool,
+    /// The maximum number of times the sinks are polled when dropped, if any
+    max_drop_polls: Option<usize>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Guard the router, closing its sinks when it is dropped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Sink;
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let router = RouterSink::shared(Vec::<usize>::new(), Vec::<usize>::new());
+    /// let left = router.left().clone();
+    /// {
+    ///     let mut router = router.guarded();
+    ///     router.start_send(Route::Left(23)).unwrap();
+    /// }
+    /// assert_eq!(*left.lock(), vec![23]);
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The guarded router
+    pub fn guarded(self) -> RouterSinkGuard<A, B> {
+        RouterSinkGuard {
+            router: Some(self),
+            closed: false,
+            max_drop_polls: None,
+        }
+    }
+}
+
+impl<A, B> RouterSinkGuard<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Set the maximum number of times the sinks are polled to close them
+    /// when the guard is dropped
+    ///
+    /// By default the sinks are polled for as long as they notify the task
+    /// while doing so. Once the maximum is reached, a warning is logged
+    /// and the remaining items are lost.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_drop_polls`: The maximum number of times the sinks are polled
+    pub fn set_max_drop_polls(&mut self, max_drop_polls: usize) {
+        self.max_drop_polls = Some(max_drop_polls);
+    }
+
+    /// Access the guarded router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the guarded router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.as_ref().expect("guard already disarmed")
+    }
+
+    /// Mutable access the guarded router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the guarded router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.as_mut().expect("guard already disarmed")
+    }
+
+    /// Disarm the guard, returning the router without closing it
+    ///
+    /// # Return value
+    ///
+    /// The guarded router
+    pub fn into_inner(mut self) -> RouterSink<A, B> {
+        self.router.take().expect("guard already disarmed")
+    }
+}
+
+impl<A, B> Sink for RouterSinkGuard<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.get_mut().start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.get_mut().poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        let result = self.get_mut().close();
+        if let Ok(Async::Ready(())) = result {
+            self.closed = true;
+        }
+        result
+    }
+}
+
+/// Records whether the task closing a dropped router was notified
+struct Notified(AtomicBool);
+
+impl Notify for Notified {
+    fn notify(&self, _id: usize) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+impl<A, B> Drop for RouterSinkGuard<A, B>
+    where A: Sink,
+          B: Sink
+{
+    fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+        let max_drop_polls = self.max_drop_polls;
+        let router = match self.router {
+            Some(ref mut router) => router,
+            None => return,
+        };
+
+        let notified = Arc::new(Notified(AtomicBool::new(false)));
+        let mut close = executor::spawn(future::poll_fn(|| router.close()));
+        let mut polls = 0;
+        while max_drop_polls != Some(polls) {
+            polls += 1;
+            notified.0.store(false, Ordering::SeqCst);
+            match close.poll_future_notify(&notified, 0) {
+                Ok(Async::Ready(())) => return,
+                Ok(Async::NotReady) if notified.0.load(Ordering::SeqCst) => {}
+                Ok(Async::NotReady) => break,
+                Err(_) => {
+                    warn!("RouterSinkGuard failed to close its sinks when dropped, \
+                           items may be lost");
+                    return;
+                }
+            }
+        }
+        warn!("RouterSinkGuard dropped before its sinks were closed, items may be lost");
+    }
+}
@@ -0,0 +1,96 @@
+use error::RouterSinkError;
+use futures::{AsyncSink, Poll, Sink, StartSend};
+use router_sink::{RouterSink, Side};
+
+/// A sink routing items to one of two sinks, calling a closure with the
+/// side of every item the router accepted
+///
+/// The closure is not called for an item handed back with `NotReady`, so
+/// it is called exactly once per item, however often the item is retried.
+pub struct InspectRouterSink<A, B, F> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// Called with the side of every accepted item
+    f: F,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Adapt the router to call the given closure with the side of every
+    /// item it accepted
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Future, Sink};
+    /// use futures_router_sink::{Route, RouterSink, Side};
+    ///
+    /// let mut sides = Vec::new();
+    /// {
+    ///     let router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new())
+    ///         .inspect(|side| sides.push(side));
+    ///     router.send(Route::Right(42)).wait().unwrap();
+    /// }
+    /// assert_eq!(sides, vec![Side::Right]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Called with the side of every accepted item
+    ///
+    /// # Return value
+    ///
+    /// The router calling the closure
+    pub fn inspect<F>(self, f: F) -> InspectRouterSink<A, B, F> {
+        InspectRouterSink { router: self, f }
+    }
+}
+
+impl<A, B, F> InspectRouterSink<A, B, F> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, F> Sink for InspectRouterSink<A, B, F>
+    where A: Sink,
+          B: Sink,
+          F: FnMut(Side)
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let side = item.side();
+        let result = self.router.start_send(item)?;
+        if let AsyncSink::Ready = result {
+            (self.f)(side);
+        }
+        Ok(result)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,45 @@
+use router_sink::Route;
+
+/// Lazily tag the items of an iterator with the route they have to take
+///
+/// The tagged items can be fed into `stream::iter_ok` and forwarded into
+/// a `RouterSink`, keeping the routing decision at the point the data is
+/// produced.
+///
+/// # Example
+///
+/// ```
+/// # extern crate futures;
+/// # extern crate futures_router_sink;
+/// # fn main() {
+/// use futures::{stream, Future, Stream};
+/// use futures_router_sink::{route_iter, Route, RouterSink, RouterSinkError};
+///
+/// let router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+/// let input = route_iter(0..10, |x| if x % 2 == 0 {
+///     Route::Left(x)
+/// } else {
+///     Route::Right(x)
+/// });
+///
+/// stream::iter_ok::<_, RouterSinkError<(), ()>>(input)
+///     .forward(router)
+///     .wait()
+///     .unwrap();
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `iter`: The items to tag
+/// - `f`: Tags an item with the route it has to take
+///
+/// # Return value
+///
+/// An iterator over the tagged items
+pub fn route_iter<I, F, L, R>(iter: I, f: F) -> impl Iterator<Item = Route<L, R>>
+    where I: IntoIterator,
+          F: FnMut(I::Item) -> Route<L, R>
+{
+    iter.into_iter().map(f)
+}
@@ -29,25 +29,257 @@
 //!     .map(Ok::<_, ()>)
 //!     .collect::<Vec<_>>();
 //!
-//! stream::iter(input)
+//! stream::iter_result(input)
 //!     .map_err(|_| RouterSinkError::Left(()))
 //!     .forward(router);
 //! # }
 //! ```
 
+#[macro_use]
 extern crate futures;
+#[macro_use]
+extern crate log;
 
+mod buffered;
+mod catch_unwind;
+#[macro_use]
+mod dispatch;
+mod drop_side;
+mod dyn_router;
+mod either;
 mod error;
+mod error_sink;
+mod filter_route;
+mod guard;
+mod inspect;
+mod iter;
+mod limits;
+mod map_item;
+mod null;
+mod partition;
+mod priority;
+mod ready;
+mod recover;
+mod retry;
+mod round_robin;
 mod router_sink;
+mod select;
+mod send_all;
+mod sequenced;
+mod shared;
+mod sharded;
+mod signal;
+mod size;
+mod timeout;
+mod transform;
 
+pub use catch_unwind::CatchUnwindRouterSink;
+pub use dispatch::{DispatchSink, RouteDispatch};
+pub use dyn_router::{DynRoute, DynRouter, DynRouterError};
+pub use either::EitherRouterSink;
 pub use error::RouterSinkError;
-pub use router_sink::{Route, RouterSink};
+pub use error_sink::ErrorSinkRouterSink;
+pub use filter_route::{FilterRouterSink, RouteDecision};
+pub use guard::RouterSinkGuard;
+pub use inspect::InspectRouterSink;
+pub use iter::route_iter;
+pub use limits::LimitedRouterSink;
+pub use map_item::MappedRouterSink;
+pub use null::NullSink;
+pub use partition::PartitionRouterSink;
+pub use priority::PriorityRouterSink;
+pub use ready::PollReady;
+pub use recover::RecoveringRouterSink;
+pub use retry::RetryRouterSink;
+pub use round_robin::RoundRobinRouterSink;
+pub use router_sink::{Bias, CountPoint, Route, RouteAccepted, RouterSink, Side};
+pub use select::route_select;
+pub use send_all::SendAll;
+pub use sequenced::SequencedRouterSink;
+pub use shared::SharedSink;
+pub use sharded::ShardedRouterSink;
+pub use signal::SignalRouterSink;
+pub use size::SizeRouterSink;
+pub use timeout::{Clock, StdClock, TimeoutRouterSink};
+pub use transform::TransformRouterSink;
 
 
 #[cfg(test)]
 mod test {
-    use super::{Route, RouterSink, RouterSinkError};
-    use futures::{Future, stream, Stream};
+    use super::{route_iter, route_select, Bias, Clock, CountPoint, DynRouter, DynRouterError, NullSink, PartitionRouterSink, RouteAccepted, RouteDecision, PollReady, Route, RouterSink, RouterSinkError,
+                ShardedRouterSink, SharedSink, Side, SignalRouterSink, TimeoutRouterSink, TransformRouterSink};
+    use std::any::Any;
+    use std::cell::{Cell, RefCell};
+    use std::error;
+    use std::fmt;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+    use futures::future::{self, Either};
+    use futures::sync::mpsc;
+    use futures::{Async, AsyncSink, Future, Poll, Sink, StartSend, stream, Stream, task};
+
+    /// Schedule the current task to be polled again, if there is one
+    fn notify() {
+        if task::is_in_task() {
+            task::current().notify();
+        }
+    }
+
+    /// A sink that refuses the first `not_ready` items it is offered
+    struct BackpressureSink<T> {
+        not_ready: usize,
+        items: Vec<T>,
+        closed: bool,
+    }
+
+    impl<T> BackpressureSink<T> {
+        fn new(not_ready: usize) -> BackpressureSink<T> {
+            BackpressureSink {
+                not_ready,
+                items: Vec::new(),
+                closed: false,
+            }
+        }
+    }
+
+    impl<T> Sink for BackpressureSink<T> {
+        type SinkItem = T;
+        type SinkError = ();
+
+        fn start_send(&mut self, item: T) -> StartSend<T, ()> {
+            if self.not_ready > 0 {
+                self.not_ready -= 1;
+                notify();
+                return Ok(AsyncSink::NotReady(item));
+            }
+            self.items.push(item);
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), ()> {
+            Ok(Async::Ready(()))
+        }
+
+        fn close(&mut self) -> Poll<(), ()> {
+            self.closed = true;
+            Ok(Async::Ready(()))
+        }
+    }
+
+    /// A sink recording its side into a shared log whenever it is flushed
+    struct RecordingSink {
+        side: Side,
+        log: Rc<RefCell<Vec<Side>>>,
+    }
+
+    impl Sink for RecordingSink {
+        type SinkItem = ();
+        type SinkError = ();
+
+        fn start_send(&mut self, _: ()) -> StartSend<(), ()> {
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), ()> {
+            self.log.borrow_mut().push(self.side);
+            Ok(Async::Ready(()))
+        }
+    }
+
+    fn recording_router() -> (RouterSink<RecordingSink, RecordingSink>, Rc<RefCell<Vec<Side>>>) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let a = RecordingSink {
+            side: Side::Left,
+            log: log.clone(),
+        };
+        let b = RecordingSink {
+            side: Side::Right,
+            log: log.clone(),
+        };
+        (RouterSink::new(a, b), log)
+    }
+
+    /// A sink buffering its items until it is flushed, flushing at most
+    /// `rate` items per `poll_complete`
+    struct BufferingSink<T> {
+        rate: usize,
+        buffer: Vec<T>,
+        items: Vec<T>,
+    }
+
+    impl<T> BufferingSink<T> {
+        fn new(rate: usize) -> BufferingSink<T> {
+            BufferingSink {
+                rate,
+                buffer: Vec::new(),
+                items: Vec::new(),
+            }
+        }
+    }
+
+    impl<T> Sink for BufferingSink<T> {
+        type SinkItem = T;
+        type SinkError = ();
+
+        fn start_send(&mut self, item: T) -> StartSend<T, ()> {
+            self.buffer.push(item);
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), ()> {
+            let n = self.rate.min(self.buffer.len());
+            self.items.extend(self.buffer.drain(..n));
+            if self.buffer.is_empty() {
+                Ok(Async::Ready(()))
+            } else {
+                notify();
+                Ok(Async::NotReady)
+            }
+        }
+    }
+
+    /// A sink failing the first `failures` flushes
+    struct FlakySink<T> {
+        failures: usize,
+        items: Vec<T>,
+    }
+
+    impl<T> FlakySink<T> {
+        fn new(failures: usize) -> FlakySink<T> {
+            FlakySink {
+                failures,
+                items: Vec::new(),
+            }
+        }
+    }
+
+    impl<T> Sink for FlakySink<T> {
+        type SinkItem = T;
+        type SinkError = ();
+
+        fn start_send(&mut self, item: T) -> StartSend<T, ()> {
+            self.items.push(item);
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), ()> {
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(());
+            }
+            Ok(Async::Ready(()))
+        }
+    }
+
+    impl<T> PollReady for BackpressureSink<T> {
+        fn poll_ready(&mut self) -> Poll<(), ()> {
+            if self.not_ready > 0 {
+                Ok(Async::NotReady)
+            } else {
+                Ok(Async::Ready(()))
+            }
+        }
+    }
 
     #[test]
     fn poll_all() {
@@ -55,7 +287,7 @@ mod test {
         let b: Vec<u32> = Vec::new();
 
         let input: Vec<Result<_, ()>> = vec![Ok(Route::Left(23)), Ok(Route::Right(42))];
-        let stream = stream::iter(input);
+        let stream = stream::iter_result(input);
 
         let router = RouterSink::new(a, b);
 
@@ -67,7 +299,7 @@ mod test {
                 assert_eq!(router.left()[0], 23);
                 assert_eq!(router.right()[0], 42);
             }
-            Err(_) => assert!(false),
+            Err(_) => panic!(),
         }
     }
 
@@ -77,15 +309,1334 @@ mod test {
         let b: Vec<u32> = Vec::new();
 
         let input = vec![Ok(Route::Left(23)), Err(())];
-        let stream = stream::iter(input);
+        let stream = stream::iter_result(input);
 
         let router = RouterSink::new(a, b);
 
-        assert_eq!(true,
-                   stream
-                       .map_err(|_| RouterSinkError::Left(()))
-                       .forward(router)
-                       .wait()
-                       .is_err());
+        assert!(stream
+                    .map_err(|_| RouterSinkError::Left(()))
+                    .forward(router)
+                    .wait()
+                    .is_err());
+    }
+
+    #[test]
+    fn start_send_not_ready() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<&'static str>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        match router.start_send(Route::Left(23)) {
+            Ok(AsyncSink::NotReady(Route::Left(23))) => {}
+            _ => panic!(),
+        }
+        match router.start_send(Route::Right("42")) {
+            Ok(AsyncSink::NotReady(Route::Right("42"))) => {}
+            _ => panic!(),
+        }
+
+        assert!(router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right("42")).unwrap().is_ready());
+        assert_eq!(router.left().items, vec![23]);
+        assert_eq!(router.right().items, vec!["42"]);
+    }
+
+    #[test]
+    fn sharded_same_key_same_side() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = ShardedRouterSink::new(a, b);
+
+        for _ in 0..10 {
+            for key in 0..100 {
+                assert!(router.start_send(key).unwrap().is_ready());
+            }
+        }
+
+        assert_eq!(router.get_ref().left().len() + router.get_ref().right().len(), 1000);
+        assert!(!router.get_ref().left().is_empty());
+        assert!(!router.get_ref().right().is_empty());
+        for key in 0..100 {
+            let left = router.get_ref().left().iter().filter(|&&x| x == key).count();
+            let right = router.get_ref().right().iter().filter(|&&x| x == key).count();
+            assert!((left, right) == (10, 0) || (left, right) == (0, 10));
+        }
+
+        let mut other = ShardedRouterSink::new(Vec::new(), Vec::new());
+        for key in 0..100 {
+            assert!(other.start_send(key).unwrap().is_ready());
+        }
+        for key in 0..100 {
+            assert_eq!(router.get_ref().left().contains(&key), other.get_ref().left().contains(&key));
+        }
+    }
+
+    #[test]
+    fn partition_by_pivot() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::partition_by(a, b, |x: &u32| *x, 10);
+
+        for x in 7..13 {
+            assert!(router.start_send(x).unwrap().is_ready());
+        }
+
+        assert_eq!(router.get_ref().left(), &vec![7, 8, 9]);
+        assert_eq!(router.get_ref().right(), &vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn poll_ready_per_side() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(0);
+        let mut router = RouterSink::new(a, b);
+
+        assert_eq!(router.poll_ready(Side::Left).ok(), Some(Async::NotReady));
+        assert_eq!(router.poll_ready(Side::Right).ok(), Some(Async::Ready(())));
+
+        assert!(!router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert_eq!(router.poll_ready(Side::Left).ok(), Some(Async::Ready(())));
+    }
+
+    #[test]
+    fn poll_ready_fallback() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::new(a, b);
+
+        assert_eq!(router.poll_ready(Side::Left).ok(), Some(Async::Ready(())));
+        assert_eq!(router.poll_ready(Side::Right).ok(), Some(Async::Ready(())));
+    }
+
+    #[test]
+    fn poll_ready_delegates() {
+        let (tx, rx) = mpsc::channel::<u32>(0);
+        let b = SharedSink::new(BackpressureSink::<u32>::new(1));
+        let inner = RouterSink::new(tx, b);
+        let mut router = RouterSink::new(inner, Vec::<u32>::new());
+
+        future::lazy(|| {
+            assert_eq!(PollReady::poll_ready(router.left_mut()).ok(), Some(Async::NotReady));
+            assert!(!router.start_send(Route::Left(Route::Right(1))).unwrap().is_ready());
+            assert!(router.left_mut().left_mut().start_send(2).unwrap().is_ready());
+            assert_eq!(router.left_mut().poll_ready(Side::Left).ok(), Some(Async::NotReady));
+            assert_eq!(router.left_mut().poll_ready(Side::Right).ok(), Some(Async::Ready(())));
+
+            drop(rx);
+            assert_eq!(router.left_mut().poll_ready(Side::Left).ok(), Some(Async::Ready(())));
+            assert!(router.start_send(Route::Left(Route::Left(3))).is_err());
+            Ok::<_, ()>(())
+        }).wait().unwrap();
+    }
+
+    #[test]
+    fn transform_not_ready_buffers_transformed_item() {
+        let text = BackpressureSink::<String>::new(1);
+        let binary = BackpressureSink::<Vec<u8>>::new(0);
+        let transforms = Cell::new(0);
+
+        let mut router = RouterSink::with_transforms(text,
+                                                     binary,
+                                                     |x: &str| {
+                                                         transforms.set(transforms.get() + 1);
+                                                         x.to_string()
+                                                     },
+                                                     |x: &str| {
+                                                         transforms.set(transforms.get() + 1);
+                                                         x.as_bytes().to_vec()
+                                                     },
+                                                     |x: &&str| if x.starts_with('t') {
+                                                         Side::Left
+                                                     } else {
+                                                         Side::Right
+                                                     });
+
+        assert!(router.start_send("text").unwrap().is_ready());
+        assert_eq!(transforms.get(), 1);
+        assert!(router.get_ref().left().items.is_empty());
+
+        assert!(router.start_send("binary").unwrap().is_ready());
+        assert_eq!(transforms.get(), 2);
+        assert_eq!(router.get_ref().left().items, vec!["text".to_string()]);
+        assert_eq!(router.get_ref().right().items, vec![b"binary".to_vec()]);
+
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(transforms.get(), 2);
+    }
+
+    #[test]
+    fn transform_not_ready_rejects_while_buffered() {
+        let text = BackpressureSink::<String>::new(2);
+        let binary = BackpressureSink::<Vec<u8>>::new(0);
+
+        let mut router = RouterSink::with_transforms(text,
+                                                     binary,
+                                                     |x: &str| x.to_string(),
+                                                     |x: &str| x.as_bytes().to_vec(),
+                                                     |_: &&str| Side::Left);
+
+        assert!(router.start_send("first").unwrap().is_ready());
+        match router.start_send("second") {
+            Ok(AsyncSink::NotReady("second")) => {}
+            _ => panic!(),
+        }
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert!(router.start_send("second").unwrap().is_ready());
+        assert_eq!(router.get_ref().left().items,
+                   vec!["first".to_string(), "second".to_string()]);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn auto_traits() {
+        type VecRouter = RouterSink<Vec<u32>, Vec<u32>>;
+        type Sharded = ShardedRouterSink<Vec<u32>, Vec<u32>>;
+        type Partition = PartitionRouterSink<Vec<u32>, Vec<u32>, fn(&u32) -> u32, u32>;
+        type Transform = TransformRouterSink<Vec<u32>,
+                                             Vec<u32>,
+                                             fn(u32) -> u32,
+                                             fn(u32) -> u32,
+                                             fn(&u32) -> Side,
+                                             u32>;
+
+        assert_send::<VecRouter>();
+        assert_sync::<VecRouter>();
+        assert_unpin::<VecRouter>();
+        assert_send::<Sharded>();
+        assert_sync::<Sharded>();
+        assert_unpin::<Sharded>();
+        assert_send::<Partition>();
+        assert_sync::<Partition>();
+        assert_unpin::<Partition>();
+        assert_send::<Transform>();
+        assert_sync::<Transform>();
+        assert_unpin::<Transform>();
+        assert_send::<RouterSinkError<(), ()>>();
+        assert_sync::<RouterSinkError<(), ()>>();
+    }
+
+    #[test]
+    fn flush_bias() {
+        let (mut router, log) = recording_router();
+        for _ in 0..2 {
+            assert!(router.poll_complete().unwrap().is_ready());
+        }
+        assert_eq!(*log.borrow(),
+                   vec![Side::Left, Side::Right, Side::Left, Side::Right]);
+
+        let (mut router, log) = recording_router();
+        router.set_flush_bias(Bias::Right);
+        for _ in 0..2 {
+            assert!(router.poll_complete().unwrap().is_ready());
+        }
+        assert_eq!(*log.borrow(),
+                   vec![Side::Right, Side::Left, Side::Right, Side::Left]);
+
+        let (mut router, log) = recording_router();
+        router.set_flush_bias(Bias::RoundRobin);
+        for _ in 0..3 {
+            assert!(router.poll_complete().unwrap().is_ready());
+        }
+        assert_eq!(*log.borrow(),
+                   vec![Side::Left, Side::Right, Side::Right, Side::Left, Side::Left, Side::Right]);
+    }
+
+    #[test]
+    fn forward_signals() {
+        let a: Vec<()> = Vec::new();
+        let b: Vec<()> = Vec::new();
+
+        let input = vec![Side::Left, Side::Right, Side::Left];
+        let router = SignalRouterSink::new(a, b);
+
+        match stream::iter_ok::<_, RouterSinkError<(), ()>>(input).forward(router).wait() {
+            Ok((_, router)) => {
+                assert_eq!(router.get_ref().left().len(), 2);
+                assert_eq!(router.get_ref().right().len(), 1);
+            }
+            Err(_) => panic!(),
+        }
+    }
+
+    #[test]
+    fn route_as_ref() {
+        let left = Route::Left::<u32, &str>(23);
+        let right = Route::Right::<u32, &str>("42");
+
+        match left.as_ref() {
+            Route::Left(&23) => {}
+            _ => panic!(),
+        }
+        match right.as_ref() {
+            Route::Right(&"42") => {}
+            _ => panic!(),
+        }
+
+        match (left, right) {
+            (Route::Left(23), Route::Right("42")) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn route_as_mut() {
+        let mut left = Route::Left::<u32, &str>(23);
+        let mut right = Route::Right::<u32, &str>("42");
+
+        if let Route::Left(x) = left.as_mut() {
+            *x += 1;
+        }
+        if let Route::Right(x) = right.as_mut() {
+            *x = "43";
+        }
+
+        match (left, right) {
+            (Route::Left(24), Route::Right("43")) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn dyn_routers_in_one_vec() {
+        let numbers = RouterSink::new(Vec::<u32>::new(), Vec::<u64>::new());
+        let strings = RouterSink::new(Vec::<String>::new(), Vec::<&'static str>::new());
+
+        let mut routers: Vec<Box<dyn DynRouter>> = vec![numbers.into_dyn(), strings.into_dyn()];
+
+        let number: Box<dyn Any> = Box::new(23u64);
+        let string: Box<dyn Any> = Box::new("42".to_string());
+        assert!(routers[0].start_send(Route::Right(number)).unwrap().is_ready());
+        assert!(routers[1].start_send(Route::Left(string)).unwrap().is_ready());
+
+        let mismatch: Box<dyn Any> = Box::new(23u64);
+        match routers[1].start_send(Route::Left(mismatch)) {
+            Err(DynRouterError::TypeMismatch(Route::Left(x))) => {
+                assert_eq!(x.downcast_ref::<u64>(), Some(&23))
+            }
+            _ => panic!(),
+        }
+
+        for router in &mut routers {
+            assert!(router.poll_complete().unwrap().is_ready());
+            assert!(router.close().unwrap().is_ready());
+        }
+
+        let mut flaky = RouterSink::new(FlakySink::<u32>::new(1), Vec::<u32>::new()).into_dyn();
+        match flaky.poll_complete() {
+            Err(DynRouterError::Router(RouterSinkError::Left(x))) => assert!(x.is::<()>()),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn drop_right() {
+        let a: Vec<u32> = Vec::new();
+        let mut router: RouterSink<Vec<u32>, NullSink<&str>> = RouterSink::new_drop_right(a);
+
+        assert!(router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right("dropped")).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(42)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        assert_eq!(router.left(), &vec![23, 42]);
+    }
+
+    #[test]
+    fn drop_left() {
+        let b: Vec<u32> = Vec::new();
+        let mut router: RouterSink<NullSink<&str>, Vec<u32>> = RouterSink::new_drop_left(b);
+
+        assert!(router.start_send(Route::Left("dropped")).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(23)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        assert_eq!(router.right(), &vec![23]);
+    }
+
+    #[test]
+    fn catch_unwind_panic() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::with_catch_unwind(a, b, |x: u32| if x == 13 {
+            panic!("unlucky")
+        } else {
+            Route::Left(x)
+        });
+
+        assert!(router.start_send(12).unwrap().is_ready());
+        match router.start_send(13) {
+            Err(RouterSinkError::Panic(x)) => assert_eq!(x, "unlucky"),
+            _ => panic!(),
+        }
+        assert!(router.start_send(14).unwrap().is_ready());
+
+        assert_eq!(router.get_ref().left(), &vec![12, 14]);
+    }
+
+    #[test]
+    fn both_mut() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::new(a, b);
+
+        {
+            let (left, right) = router.both_mut();
+            left.push(23);
+            right.push(42);
+            left.push(right[0]);
+        }
+
+        assert_eq!(router.left(), &vec![23, 42]);
+        assert_eq!(router.right(), &vec![42]);
+    }
+
+    #[test]
+    fn by_size_threshold() {
+        let fast: Vec<&str> = Vec::new();
+        let bulk: Vec<&str> = Vec::new();
+        let mut router = RouterSink::by_size(fast, bulk, 4, |x: &&str| x.len());
+
+        for x in &["abc", "abcd", "abcde"] {
+            assert!(router.start_send(x).unwrap().is_ready());
+        }
+
+        assert_eq!(router.get_ref().left(), &vec!["abc", "abcd"]);
+        assert_eq!(router.get_ref().right(), &vec!["abcde"]);
+    }
+
+    #[test]
+    fn route_iter_tags_lazily() {
+        let calls = Cell::new(0);
+        let tagged = route_iter(0..4, |x| {
+            calls.set(calls.get() + 1);
+            if x % 2 == 0 {
+                Route::Left(x)
+            } else {
+                Route::Right(x)
+            }
+        });
+        assert_eq!(calls.get(), 0);
+
+        let tagged = tagged
+            .map(|x| match x {
+                     Route::Left(x) => (Side::Left, x),
+                     Route::Right(x) => (Side::Right, x),
+                 })
+            .collect::<Vec<_>>();
+
+        assert_eq!(calls.get(), 4);
+        assert_eq!(tagged,
+                   vec![(Side::Left, 0), (Side::Right, 1), (Side::Left, 2), (Side::Right, 3)]);
+    }
+
+    #[test]
+    fn retry_transient_error() {
+        let a = FlakySink::<u32>::new(2);
+        let b = FlakySink::<u32>::new(0);
+        let router = RouterSink::new_retry(a, b, |_: &RouterSinkError<(), ()>| true);
+
+        let router = router
+            .send(Route::Left(23))
+            .wait()
+            .unwrap_or_else(|_| panic!());
+
+        assert_eq!(router.get_ref().left().items, vec![23]);
+        assert_eq!(router.get_ref().left().failures, 0);
+    }
+
+    /// A sink alternating between failing and pending flushes
+    struct AlternatingSink {
+        polls: usize,
+    }
+
+    impl Sink for AlternatingSink {
+        type SinkItem = u32;
+        type SinkError = ();
+
+        fn start_send(&mut self, _item: u32) -> StartSend<u32, ()> {
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), ()> {
+            self.polls += 1;
+            if self.polls % 2 == 1 {
+                return Err(());
+            }
+            notify();
+            Ok(Async::NotReady)
+        }
+    }
+
+    #[test]
+    fn retry_not_reset_by_not_ready() {
+        let a = AlternatingSink { polls: 0 };
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::new_retry(a, b, |_: &RouterSinkError<(), ()>| true);
+
+        match future::poll_fn(|| router.poll_complete()).wait() {
+            Err(RouterSinkError::Left(())) => {}
+            _ => panic!(),
+        }
+        assert_eq!(router.get_ref().left().polls, 7);
+    }
+
+    #[test]
+    fn retry_outside_task() {
+        let a = FlakySink::<u32>::new(1);
+        let b = FlakySink::<u32>::new(0);
+        let mut router = RouterSink::new_retry(a, b, |_: &RouterSinkError<(), ()>| true);
+
+        assert_eq!(router.poll_complete().ok(), Some(Async::NotReady));
+        assert_eq!(router.poll_complete().ok(), Some(Async::Ready(())));
+    }
+
+    #[test]
+    fn retry_exhausted() {
+        let a = FlakySink::<u32>::new(0);
+        let b = FlakySink::<u32>::new(3);
+        let mut router = RouterSink::new_retry(a, b, |_: &RouterSinkError<(), ()>| true);
+        router.set_max_retries(2);
+
+        match router.send(Route::Right(42)).wait() {
+            Err(RouterSinkError::Right(())) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn retry_not_retryable() {
+        let a = FlakySink::<u32>::new(1);
+        let b = FlakySink::<u32>::new(0);
+        let router = RouterSink::new_retry(a, b, |e: &RouterSinkError<(), ()>| {
+            matches!(*e, RouterSinkError::Right(_))
+        });
+
+        match router.send(Route::Left(23)).wait() {
+            Err(RouterSinkError::Left(())) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn round_robin_alternates() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(1);
+        let mut router = RouterSink::round_robin(a, b);
+
+        for x in 0..6 {
+            while !router.start_send(x).unwrap().is_ready() {}
+        }
+
+        assert_eq!(router.get_ref().left().items, vec![0, 2, 4]);
+        assert_eq!(router.get_ref().right().items, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn side_opposite() {
+        assert_eq!(Side::Left.opposite(), Side::Right);
+        assert_eq!(Side::Right.opposite(), Side::Left);
+        assert_eq!(Side::Left.opposite().opposite(), Side::Left);
+    }
+
+    #[test]
+    fn route_side() {
+        assert_eq!(Route::Left::<u32, &str>(23).side(), Side::Left);
+        assert_eq!(Route::Right::<u32, &str>("42").side(), Side::Right);
+        assert_eq!(Route::from_side(Side::Left, 23).side(), Side::Left);
+        assert_eq!(Route::from_side(Side::Right, 42).side(), Side::Right);
+    }
+
+    #[test]
+    fn error_counts() {
+        let a = FlakySink::<u32>::new(2);
+        let b = FlakySink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.poll_complete().is_err());
+        assert_eq!(router.error_counts(), (1, 1));
+        assert!(router.close().is_err());
+        assert_eq!(router.error_counts(), (2, 1));
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.error_counts(), (2, 1));
+    }
+
+    #[test]
+    fn share_right() {
+        let left_a: Vec<u32> = Vec::new();
+        let left_b: Vec<&str> = Vec::new();
+        let right: Vec<u32> = Vec::new();
+        let mut router = RouterSink::share_right(left_a, left_b, right);
+
+        assert!(router.start_send(Route::Left(Either::A(23))).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(Either::B("42"))).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(5)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        assert_eq!(router.left().get_ref().left(), &vec![23]);
+        assert_eq!(router.left().get_ref().right(), &vec!["42"]);
+        assert_eq!(router.right(), &vec![5]);
+
+        let left_a = BackpressureSink::<u32>::new(0);
+        let left_b = BackpressureSink::<&str>::new(1);
+        let right: Vec<u32> = Vec::new();
+        let mut router = RouterSink::share_right(left_a, left_b, right);
+        match router.start_send(Route::Left(Either::B("42"))) {
+            Ok(AsyncSink::NotReady(Route::Left(Either::B("42")))) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn count_on_accepted() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(3)).unwrap().is_ready());
+        assert_eq!(router.success_counts(), (2, 1));
+
+        assert!(!router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.success_counts(), (2, 1));
+    }
+
+    #[test]
+    fn count_on_flushed() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+        router.set_count_point(CountPoint::Flushed);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(3)).unwrap().is_ready());
+        assert_eq!(router.success_counts(), (0, 0));
+
+        assert!(!router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.success_counts(), (0, 1));
+
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.success_counts(), (2, 1));
+    }
+
+    #[test]
+    fn map_item_caches_mapped_item() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(0);
+        let calls = Cell::new(0);
+        let mut router = RouterSink::new(a, b).map_item(|x: u32| {
+            calls.set(calls.get() + 1);
+            Route::Left(x)
+        });
+
+        assert!(router.start_send(23).unwrap().is_ready());
+        assert!(router.get_ref().left().items.is_empty());
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(router.get_ref().left().items, vec![23]);
+    }
+
+    #[test]
+    fn send_all_routes() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let router = RouterSink::new(a, b);
+
+        let items = vec![Route::Left(1), Route::Right(2), Route::Left(3), Route::Right(4)];
+        let router = router
+            .send_all_routes(items)
+            .wait()
+            .unwrap_or_else(|_| panic!());
+
+        assert_eq!(router.left().items, vec![1, 3]);
+        assert_eq!(router.right().items, vec![2, 4]);
+    }
+
+    #[test]
+    fn poll_health() {
+        let a = FlakySink::<u32>::new(0);
+        let b = FlakySink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        match router.poll_health() {
+            Err(RouterSinkError::Right(())) => {}
+            _ => panic!(),
+        }
+        assert_eq!(router.poll_health().ok(), Some(Async::Ready(())));
+
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        assert_eq!(router.poll_health().ok(), Some(Async::NotReady));
+        assert_eq!(router.poll_health().ok(), Some(Async::Ready(())));
+    }
+
+    #[test]
+    fn filter_route_skips() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::filter_route(a, b, |x: u32| match x % 3 {
+            0 => RouteDecision::Skip,
+            1 => RouteDecision::Left(x),
+            _ => RouteDecision::Right(x),
+        });
+
+        for x in 0..7 {
+            assert!(router.start_send(x).unwrap().is_ready());
+        }
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        assert_eq!(router.get_ref().left(), &vec![1, 4]);
+        assert_eq!(router.get_ref().right(), &vec![2, 5]);
+    }
+
+    #[test]
+    fn filter_route_close() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(0);
+        let mut router = RouterSink::filter_route(a, b, RouteDecision::Left);
+
+        assert!(router.start_send(23).unwrap().is_ready());
+        assert!(router.close().unwrap().is_ready());
+        assert_eq!(router.get_ref().left().items, vec![23]);
+        assert!(router.get_ref().left().closed && router.get_ref().right().closed);
+    }
+
+    #[test]
+    fn shared_clones() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::shared(a, b);
+        let mut other = router.clone();
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(other.start_send(Route::Left(2)).unwrap().is_ready());
+        assert!(other.start_send(Route::Right(3)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert!(other.poll_complete().unwrap().is_ready());
+
+        assert_eq!(*router.left().lock(), vec![1, 2]);
+        assert_eq!(*other.right().lock(), vec![3]);
+    }
+
+    #[test]
+    fn try_route_rejected() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(0);
+        let mut router = RouterSink::new(a, b);
+
+        let item = match router.try_route(Route::Left(23)) {
+            Ok(RouteAccepted::Rejected(x)) => x,
+            _ => panic!(),
+        };
+        match router.try_route(Route::Right(42)) {
+            Ok(RouteAccepted::Accepted) => {}
+            _ => panic!(),
+        }
+        match router.try_route(item) {
+            Ok(RouteAccepted::Accepted) => {}
+            _ => panic!(),
+        }
+
+        assert_eq!(router.left().items, vec![23]);
+        assert_eq!(router.right().items, vec![42]);
+    }
+
+    #[test]
+    fn poll_drain_interleaves() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(3);
+        let mut router = RouterSink::new(a, b);
+
+        for x in 0..3 {
+            assert!(router.start_send(Route::Left(x)).unwrap().is_ready());
+            assert!(router.start_send(Route::Right(x)).unwrap().is_ready());
+        }
+
+        assert_eq!(router.poll_drain().ok(), Some(Async::NotReady));
+        assert_eq!(router.left().items, vec![0, 1]);
+        assert_eq!(router.right().items, vec![0, 1, 2]);
+
+        assert_eq!(router.poll_drain().ok(), Some(Async::Ready(())));
+        assert_eq!(router.left().items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn route_parts_round_trip() {
+        let (side, item) = Route::Left(23).into_parts();
+        assert_eq!((side, item), (Side::Left, 23));
+        assert_eq!(Route::from_parts(side, item).into_parts(), (Side::Left, 23));
+
+        let (side, item) = Route::Right("42").into_parts();
+        assert_eq!((side, item), (Side::Right, "42"));
+        assert_eq!(Route::from_parts(side, item).into_parts(), (Side::Right, "42"));
+    }
+
+    /// A clock that only moves when told to
+    struct ManualClock {
+        now: Rc<Cell<Instant>>,
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn flush_timeout() {
+        let a = BufferingSink::<u32>::new(0);
+        let b = BufferingSink::<u32>::new(1);
+        let now = Rc::new(Cell::new(Instant::now()));
+        let clock = ManualClock { now: now.clone() };
+        let mut router = TimeoutRouterSink::new(a, b, Duration::from_millis(100), clock);
+
+        assert!(router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(42)).unwrap().is_ready());
+        assert_eq!(router.poll_complete().ok(), Some(Async::NotReady));
+
+        now.set(now.get() + Duration::from_millis(50));
+        assert_eq!(router.poll_complete().ok(), Some(Async::NotReady));
+        assert_eq!(router.get_ref().right().items, vec![42]);
+
+        now.set(now.get() + Duration::from_millis(50));
+        match router.poll_complete() {
+            Err(RouterSinkError::FlushTimeout(Side::Left)) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn pending_counts() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(2);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(3)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(4)).unwrap().is_ready());
+        assert_eq!(router.pending_counts(), (2, 2));
+
+        assert!(!router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.pending_counts(), (2, 0));
+
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.pending_counts(), (0, 0));
+    }
+
+    #[test]
+    fn error_sink() {
+        let a = FlakySink::<u32>::new(1);
+        let b = FlakySink::<u32>::new(0);
+        let errors = Vec::<RouterSinkError<(), ()>>::new();
+        let mut router = RouterSink::with_error_sink(a, b, errors);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(2)).unwrap().is_ready());
+        assert_eq!(router.poll_complete(), Ok(Async::Ready(())));
+        match router.err_sink()[..] {
+            [RouterSinkError::Left(())] => {}
+            _ => panic!(),
+        }
+
+        assert!(router.start_send(Route::Left(3)).unwrap().is_ready());
+        assert_eq!(router.poll_complete(), Ok(Async::Ready(())));
+        assert_eq!(router.get_ref().left().items, vec![1, 3]);
+        assert_eq!(router.get_ref().right().items, vec![2]);
+        assert_eq!(router.err_sink().len(), 1);
+    }
+
+    #[test]
+    fn error_sink_fails() {
+        let a = FlakySink::<u32>::new(1);
+        let b = FlakySink::<u32>::new(0);
+        let errors = FlakySink::<RouterSinkError<(), ()>>::new(1);
+        let mut router = RouterSink::with_error_sink(a, b, errors);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert_eq!(router.poll_complete(), Err(()));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct SinkFailed(&'static str);
+
+    impl fmt::Display for SinkFailed {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} sink failed", self.0)
+        }
+    }
+
+    impl error::Error for SinkFailed {}
+
+    /// An application error embedding routing errors
+    #[derive(Debug)]
+    enum AppError {
+        Sink(SinkFailed),
+        Routing(RouterSinkError<SinkFailed, SinkFailed>),
+    }
+
+    impl From<SinkFailed> for AppError {
+        fn from(error: SinkFailed) -> AppError {
+            AppError::Sink(error)
+        }
+    }
+
+    impl From<RouterSinkError<SinkFailed, SinkFailed>> for AppError {
+        fn from(error: RouterSinkError<SinkFailed, SinkFailed>) -> AppError {
+            AppError::Routing(error)
+        }
+    }
+
+    impl fmt::Display for AppError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "application failed")
+        }
+    }
+
+    impl error::Error for AppError {
+        fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+            match *self {
+                AppError::Sink(ref x) => Some(x),
+                AppError::Routing(ref x) => Some(x),
+            }
+        }
+    }
+
+    #[test]
+    fn error_conversions() {
+        let error = RouterSinkError::<SinkFailed, SinkFailed>::Right(SinkFailed("right"));
+        let (left, right) = error.into();
+        assert_eq!(left, None);
+        assert_eq!(right, Some(SinkFailed("right")));
+
+        let error = RouterSinkError::<SinkFailed, SinkFailed>::Left(SinkFailed("left"));
+        match error.into_unified::<AppError>() {
+            Ok(AppError::Sink(SinkFailed("left"))) => {}
+            _ => panic!(),
+        }
+
+        let error = RouterSinkError::<SinkFailed, SinkFailed>::FlushTimeout(Side::Left);
+        match error.into_unified::<AppError>() {
+            Err(RouterSinkError::FlushTimeout(Side::Left)) => {}
+            _ => panic!(),
+        }
+
+        let error: AppError = RouterSinkError::Left(SinkFailed("left")).into();
+        let mut messages = vec![error.to_string()];
+        let mut source = error::Error::source(&error);
+        while let Some(x) = source {
+            messages.push(x.to_string());
+            source = x.source();
+        }
+        assert_eq!(messages, vec!["application failed", "left sink failed"]);
+    }
+
+    #[test]
+    fn route_branch() {
+        match Route::branch(42, |x| x % 2 == 0) {
+            Route::Left(42) => {}
+            _ => panic!(),
+        }
+        match Route::branch(23, |x| x % 2 == 0) {
+            Route::Right(23) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn flush_progress() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(2);
+        let mut router = RouterSink::new(a, b);
+
+        for i in 0..3 {
+            assert!(router.start_send(Route::Left(i)).unwrap().is_ready());
+            assert!(router.start_send(Route::Right(i)).unwrap().is_ready());
+        }
+
+        let mut total = (0, 0);
+        let mut progress = Vec::new();
+        while router.pending_counts() != (0, 0) {
+            let (left, right) = router.flush_progress().unwrap();
+            progress.push((left, right));
+            total.0 += left;
+            total.1 += right;
+        }
+        assert_eq!(progress, vec![(0, 0), (0, 3), (3, 0)]);
+        assert_eq!(total, (3, 3));
+        assert_eq!(router.flush_progress().unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn route_with_moves_once() {
+        let a = BackpressureSink::<String>::new(2);
+        let b = BackpressureSink::<usize>::new(2);
+        let mapped = RefCell::new(Vec::new());
+        let mut router = RouterSink::route_with(a, b, |x: String| {
+            if mapped.borrow().contains(&x) {
+                panic!("{} mapped twice", x);
+            }
+            mapped.borrow_mut().push(x.clone());
+            match x.parse() {
+                Ok(x) => Route::Right(x),
+                Err(_) => Route::Left(x),
+            }
+        });
+
+        for x in &["a", "1", "b", "2"] {
+            let mut item = x.to_string();
+            while let AsyncSink::NotReady(x) = router.start_send(item).unwrap() {
+                item = x;
+                router.poll_complete().unwrap();
+            }
+        }
+        while !router.poll_complete().unwrap().is_ready() {}
+
+        assert_eq!(mapped.borrow().len(), 4);
+        assert_eq!(router.get_ref().left().items, vec!["a", "b"]);
+        assert_eq!(router.get_ref().right().items, vec![1, 2]);
+    }
+
+    #[test]
+    fn seal_left() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        assert!(!router.seal_left().unwrap().is_ready());
+        assert!(!router.is_sealed(Side::Left));
+        assert!(router.seal_left().unwrap().is_ready());
+        assert!(router.is_sealed(Side::Left));
+        assert_eq!(router.left().items, vec![1, 2]);
+
+        match router.start_send(Route::Left(3)) {
+            Err(RouterSinkError::SideSealed(Side::Left)) => {}
+            _ => panic!(),
+        }
+        assert!(router.start_send(Route::Right(4)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.left().items, vec![1, 2]);
+        assert_eq!(router.right().items, vec![4]);
+        assert_eq!(router.error_counts(), (0, 0));
+    }
+
+    #[test]
+    fn from_array() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::from([a, b]);
+
+        assert!(router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(42)).unwrap().is_ready());
+        assert_eq!(router.left(), &vec![23]);
+        assert_eq!(router.right(), &vec![42]);
+    }
+
+    #[test]
+    fn start_send_tracked() {
+        let a = BackpressureSink::<u32>::new(1);
+        let b = BackpressureSink::<u32>::new(0);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.start_send_tracked(Route::Right(1)).unwrap().is_ready());
+        assert!(!router.start_send_tracked(Route::Left(2)).unwrap().is_ready());
+        assert!(router.start_send_tracked(Route::Left(2)).unwrap().is_ready());
+        assert_eq!(router.take_receipts(), vec![(0, Side::Right), (1, Side::Left)]);
+
+        assert!(router.start_send(Route::Left(3)).unwrap().is_ready());
+        assert!(router.start_send_tracked(Route::Right(4)).unwrap().is_ready());
+        assert_eq!(router.take_receipts(), vec![(2, Side::Right)]);
+    }
+
+    #[test]
+    fn left_only() {
+        let a: Vec<u32> = Vec::new();
+        let mut router = RouterSink::left_only(a);
+
+        assert!(router.start_send(Route::Left(23)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right("dropped")).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.left(), &vec![23]);
+    }
+
+    #[test]
+    fn right_only() {
+        let b: Vec<u32> = Vec::new();
+        let mut router = RouterSink::right_only(b);
+
+        assert!(router.start_send(Route::Left("dropped")).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(42)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.right(), &vec![42]);
+    }
+
+    #[test]
+    fn replace_both() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::new(a, b);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(2)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+
+        let (old_a, old_b) = router.replace_both(BufferingSink::new(1), BufferingSink::new(1));
+        assert_eq!(old_a.items, vec![1]);
+        assert_eq!(old_b.items, vec![2]);
+
+        assert!(router.start_send(Route::Left(3)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(4)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.left().items, vec![3]);
+        assert_eq!(router.right().items, vec![4]);
+        assert_eq!(router.success_counts(), (2, 2));
+    }
+
+    #[test]
+    fn recover_collects_errors() {
+        let a = FlakySink::<u32>::new(1);
+        let b = FlakySink::<u32>::new(1);
+        let router = RouterSink::new(a, b).recover();
+
+        let items = vec![Route::Left(1), Route::Right(2), Route::Left(3)];
+        let mut router = stream::iter_ok::<_, ()>(items)
+            .forward(router)
+            .wait()
+            .unwrap()
+            .1;
+
+        match router.take_errors()[..] {
+            [RouterSinkError::Left(()), RouterSinkError::Right(())] => {}
+            _ => panic!(),
+        }
+        assert!(router.take_errors().is_empty());
+        assert_eq!(router.get_ref().left().items, vec![1, 3]);
+        assert_eq!(router.get_ref().right().items, vec![2]);
+    }
+
+    #[test]
+    fn priority_flushes_high_side_first() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::with_priority(a, b, Side::Right);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(2)).unwrap().is_ready());
+        assert!(router.start_send(Route::Right(3)).unwrap().is_ready());
+
+        assert!(!router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.get_ref().right().items, vec![2]);
+        assert!(router.get_ref().left().items.is_empty());
+
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.get_ref().right().items, vec![2, 3]);
+        assert_eq!(router.get_ref().left().items, vec![1]);
+    }
+
+    #[test]
+    fn route_select_uneven_streams() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BackpressureSink::<&str>::new(1);
+        let router = RouterSink::new(a, b);
+
+        let left = stream::iter_ok::<_, RouterSinkError<(), ()>>(vec![1, 2, 3, 4]);
+        let right = stream::iter_ok(vec!["a"]);
+        let router = route_select(left, right, router).wait().unwrap();
+
+        assert_eq!(router.left().items, vec![1, 2, 3, 4]);
+        assert_eq!(router.right().items, vec!["a"]);
+        assert_eq!(router.pending_counts(), (0, 0));
+    }
+
+    #[test]
+    fn in_flight_limits() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(1);
+        let mut router = RouterSink::with_limits(a, b, 2, 1);
+
+        assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        assert!(router.start_send(Route::Left(2)).unwrap().is_ready());
+        match router.start_send(Route::Left(3)).unwrap() {
+            AsyncSink::NotReady(Route::Left(3)) => {}
+            _ => panic!(),
+        }
+        assert!(router.start_send(Route::Right(4)).unwrap().is_ready());
+        assert_eq!(router.get_ref().pending_counts(), (2, 1));
+
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.get_ref().pending_counts(), (0, 0));
+        assert!(router.start_send(Route::Left(3)).unwrap().is_ready());
+        assert!(router.poll_complete().unwrap().is_ready());
+        assert_eq!(router.get_ref().left().items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn inspect_once_per_item() {
+        let a = BackpressureSink::<u32>::new(2);
+        let b = BackpressureSink::<u32>::new(1);
+        let sides = RefCell::new(Vec::new());
+        let router = RouterSink::new(a, b).inspect(|side| sides.borrow_mut().push(side));
+
+        let items = vec![Route::Left(1), Route::Right(2), Route::Left(3)];
+        let router = stream::iter_ok::<_, RouterSinkError<(), ()>>(items)
+            .forward(router)
+            .wait()
+            .unwrap()
+            .1;
+
+        assert_eq!(*sides.borrow(), vec![Side::Left, Side::Right, Side::Left]);
+        assert_eq!(router.get_ref().left().items, vec![1, 3]);
+        assert_eq!(router.get_ref().right().items, vec![2]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct LogLine(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    struct Metric(u64);
+
+    route_dispatch!(LogLine, Metric);
+
+    #[test]
+    fn dispatch_by_type() {
+        let a = BackpressureSink::<LogLine>::new(1);
+        let b = BackpressureSink::<Metric>::new(0);
+        let mut router = RouterSink::new(a, b).into_dispatch();
+
+        assert!(router.start_dispatch(Metric(23)).unwrap().is_ready());
+        match router.start_dispatch(LogLine("hello")).unwrap() {
+            AsyncSink::NotReady(route) => {
+                assert!(router.start_send(route).unwrap().is_ready());
+            }
+            AsyncSink::Ready => panic!(),
+        }
+
+        let router = router.send(Metric(42)).wait().unwrap();
+        let router = router.send(LogLine("world")).wait().unwrap();
+        assert_eq!(router.get_ref().left().items, vec![LogLine("hello"), LogLine("world")]);
+        assert_eq!(router.get_ref().right().items, vec![Metric(23), Metric(42)]);
+    }
+
+    #[test]
+    fn sequence_numbers_across_sides() {
+        let a = BackpressureSink::<(u64, u32)>::new(1);
+        let b = BackpressureSink::<(u64, u32)>::new(2);
+        let router = RouterSink::new(a, b).sequenced(|seq, x| (seq, x));
+
+        let items = vec![Route::Left(10), Route::Right(11), Route::Right(12), Route::Left(13)];
+        let router = stream::iter_ok::<_, RouterSinkError<(), ()>>(items)
+            .forward(router)
+            .wait()
+            .unwrap()
+            .1;
+
+        assert_eq!(router.get_ref().left().items, vec![(0, 10), (3, 13)]);
+        assert_eq!(router.get_ref().right().items, vec![(1, 11), (2, 12)]);
+    }
+
+    #[test]
+    fn guard_flushes_on_drop() {
+        let router = RouterSink::shared(BufferingSink::<u32>::new(1), BufferingSink::<u32>::new(2));
+        let a = router.left().clone();
+        let b = router.right().clone();
+
+        {
+            let mut router = router.guarded();
+            for i in 0..3 {
+                assert!(router.start_send(Route::Left(i)).unwrap().is_ready());
+                assert!(router.start_send(Route::Right(i)).unwrap().is_ready());
+            }
+            assert!(a.lock().items.is_empty());
+        }
+
+        assert_eq!(a.lock().items, vec![0, 1, 2]);
+        assert_eq!(b.lock().items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn guard_flushes_slow_sink_on_drop() {
+        let router = RouterSink::shared(BufferingSink::<u32>::new(1), BufferingSink::<u32>::new(1));
+        let a = router.left().clone();
+
+        {
+            let mut router = router.guarded();
+            for i in 0..100 {
+                assert!(router.start_send(Route::Left(i)).unwrap().is_ready());
+            }
+        }
+
+        assert_eq!(a.lock().items, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn guard_gives_up_on_drop() {
+        let router = RouterSink::shared(BufferingSink::<u32>::new(0), BufferingSink::<u32>::new(0));
+        let a = router.left().clone();
+
+        {
+            let mut router = router.guarded();
+            router.set_max_drop_polls(4);
+            assert!(router.start_send(Route::Left(1)).unwrap().is_ready());
+        }
+
+        assert!(a.lock().items.is_empty());
+        assert_eq!(a.lock().buffer, vec![1]);
+    }
+
+    #[test]
+    fn wrappers_close_inner_sinks() {
+        let a = BackpressureSink::<u32>::new(0);
+        let b = BackpressureSink::<u32>::new(0);
+        let mut router = ShardedRouterSink::new(a, b);
+        assert!(router.close().unwrap().is_ready());
+        assert!(router.get_ref().left().closed && router.get_ref().right().closed);
+
+        let text = BackpressureSink::<String>::new(1);
+        let binary = BackpressureSink::<Vec<u8>>::new(0);
+        let mut router = RouterSink::with_transforms(text,
+                                                     binary,
+                                                     |x: &str| x.to_string(),
+                                                     |x: &str| x.as_bytes().to_vec(),
+                                                     |_: &&str| Side::Left);
+        assert!(router.start_send("text").unwrap().is_ready());
+        assert!(router.close().unwrap().is_ready());
+        assert_eq!(router.get_ref().left().items, vec!["text".to_string()]);
+        assert!(router.get_ref().left().closed && router.get_ref().right().closed);
+    }
+
+    #[test]
+    fn close_timeout() {
+        let a = BufferingSink::<u32>::new(1);
+        let b = BufferingSink::<u32>::new(0);
+        let now = Rc::new(Cell::new(Instant::now()));
+        let clock = ManualClock { now: now.clone() };
+        let mut router = TimeoutRouterSink::new(a, b, Duration::from_millis(100), clock);
+
+        assert!(router.start_send(Route::Right(42)).unwrap().is_ready());
+        assert_eq!(router.close().ok(), Some(Async::NotReady));
+
+        now.set(now.get() + Duration::from_millis(100));
+        match router.close() {
+            Err(RouterSinkError::FlushTimeout(Side::Right)) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "in-flight limits must be positive")]
+    fn zero_in_flight_limit() {
+        let a: Vec<u32> = Vec::new();
+        let b: Vec<u32> = Vec::new();
+        RouterSink::with_limits(a, b, 1, 0);
     }
 }
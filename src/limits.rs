@@ -0,0 +1,117 @@
+use error::RouterSinkError;
+use futures::{AsyncSink, Poll, Sink, StartSend};
+use router_sink::{RouterSink, Side};
+
+/// A sink routing items to one of two sinks, bounding the number of items
+/// in flight for each of them
+///
+/// Once the configured number of items routed to a side is pending, i.e.
+/// accepted but not yet confirmed flushed, that side's sink is flushed
+/// before it is sent another item. If it still has as many items pending,
+/// the item is handed back with `NotReady`, even if the sink itself would
+/// accept it. Items routed to the other side are unaffected.
+pub struct LimitedRouterSink<A, B> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// The maximum number of items pending in the left sink
+    left_max: usize,
+    /// The maximum number of items pending in the right sink
+    right_max: usize,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new LimitedRouterSink for the two given sinks, with the
+    /// given maximum numbers of items in flight
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::with_limits(left, right, 16, 4);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    /// - `left_max`: The maximum number of items pending in the left sink
+    /// - `right_max`: The maximum number of items pending in the right sink
+    ///
+    /// # Panics
+    ///
+    /// If either limit is zero, as no item could ever be sent to that side
+    pub fn with_limits(left_sink: A,
+                       right_sink: B,
+                       left_max: usize,
+                       right_max: usize)
+                       -> LimitedRouterSink<A, B> {
+        assert!(left_max > 0 && right_max > 0, "in-flight limits must be positive");
+        LimitedRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            left_max,
+            right_max,
+        }
+    }
+}
+
+impl<A, B> LimitedRouterSink<A, B> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+
+    /// Whether the sink for the given side has reached its limit
+    fn at_limit(&self, side: Side) -> bool {
+        let (left, right) = self.router.pending_counts();
+        match side {
+            Side::Left => left >= self.left_max,
+            Side::Right => right >= self.right_max,
+        }
+    }
+}
+
+impl<A, B> Sink for LimitedRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let side = item.side();
+        if self.at_limit(side) {
+            // Flush the sink first, so it makes progress, or the current
+            // task is notified once it can
+            self.router.poll_complete_side(side)?;
+            if self.at_limit(side) {
+                return Ok(AsyncSink::NotReady(item));
+            }
+        }
+        self.router.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
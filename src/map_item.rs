@@ -0,0 +1,162 @@
+use buffered::BufferedRouter;
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::marker::PhantomData;
+
+/// A router accepting items of its own type, mapped into routed items by
+/// a closure before they are sent
+///
+/// If the chosen sink is not ready to accept an already mapped item, the
+/// mapped item is buffered and sent before any other item, so the closure
+/// is never called twice for the same item.
+pub struct MappedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the mapped items are sent to
+    router: BufferedRouter<A, B>,
+    /// Maps an item into a routed item
+    f: F,
+    /// The item type accepted by the router
+    item: PhantomData<fn(T)>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Adapt the router to accept items of another type, mapped into
+    /// routed items by the given closure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Future, Sink};
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// enum Msg {
+    ///     Log(String),
+    ///     Metric(u64),
+    /// }
+    ///
+    /// let router = RouterSink::new(Vec::<String>::new(), Vec::<u64>::new())
+    ///     .map_item(|x| match x {
+    ///         Msg::Log(x) => Route::Left(x),
+    ///         Msg::Metric(x) => Route::Right(x),
+    ///     });
+    ///
+    /// let router = router.send(Msg::Metric(23)).wait().unwrap();
+    /// assert_eq!(router.get_ref().right(), &vec![23]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Maps an item into a routed item
+    ///
+    /// # Return value
+    ///
+    /// The router accepting items of the closure's argument type
+    pub fn map_item<T, F>(self, f: F) -> MappedRouterSink<A, B, F, T>
+        where F: FnMut(T) -> Route<A::SinkItem, B::SinkItem>
+    {
+        MappedRouterSink {
+            router: BufferedRouter::new(self),
+            f,
+            item: PhantomData,
+        }
+    }
+
+    /// Create a new router for the two given sinks, accepting items mapped
+    /// into routed items by the given closure
+    ///
+    /// The closure both decides the route of an item and produces the item
+    /// sent to the chosen sink. It is called exactly once per item, even if
+    /// the chosen sink is not ready to accept the mapped item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Future, Sink};
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let left = Vec::<String>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::route_with(left, right, |x: String| match x.parse() {
+    ///     Ok(x) => Route::Right(x),
+    ///     Err(_) => Route::Left(x),
+    /// });
+    ///
+    /// let router = router.send("42".to_string()).wait().unwrap();
+    /// assert_eq!(router.get_ref().right(), &vec![42]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is mapped to `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is mapped to `Right`
+    /// - `f`: Maps an item into a routed item
+    ///
+    /// # Return value
+    ///
+    /// The router accepting items of the closure's argument type
+    pub fn route_with<T, F>(left_sink: A, right_sink: B, f: F) -> MappedRouterSink<A, B, F, T>
+        where F: FnMut(T) -> Route<A::SinkItem, B::SinkItem>
+    {
+        RouterSink::new(left_sink, right_sink).map_item(f)
+    }
+}
+
+impl<A, B, F, T> MappedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.get_ref()
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.get_mut()
+    }
+}
+
+impl<A, B, F, T> Sink for MappedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink,
+          F: FnMut(T) -> Route<A::SinkItem, B::SinkItem>
+{
+    type SinkItem = T;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let f = &mut self.f;
+        self.router.start_send_with(item, |item| Ok(Some(f(item))))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,96 @@
+use futures::{Async, AsyncSink, Poll, Sink, StartSend};
+use ready::PollReady;
+use router_sink::RouterSink;
+use std::marker::PhantomData;
+
+/// A sink accepting and silently discarding all items, never failing
+pub struct NullSink<T> {
+    /// The item type of the discarded items
+    item: PhantomData<fn(T)>,
+}
+
+impl<T> NullSink<T> {
+    /// Create a new NullSink
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::NullSink;
+    ///
+    /// let sink = NullSink::<usize>::new();
+    /// ```
+    pub fn new() -> NullSink<T> {
+        NullSink { item: PhantomData }
+    }
+}
+
+impl<T> Default for NullSink<T> {
+    fn default() -> NullSink<T> {
+        NullSink::new()
+    }
+}
+
+impl<T> Clone for NullSink<T> {
+    fn clone(&self) -> NullSink<T> {
+        NullSink::new()
+    }
+}
+
+impl<T> Sink for NullSink<T> {
+    type SinkItem = T;
+    type SinkError = ();
+
+    fn start_send(&mut self, _item: T) -> StartSend<T, ()> {
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), ()> {
+        Ok(Async::Ready(()))
+    }
+}
+
+impl<T> PollReady for NullSink<T> {}
+
+impl<A, T> RouterSink<A, NullSink<T>> {
+    /// Create a new RouterSink delivering left routed items to the given
+    /// sink and discarding right routed items of type `T`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{NullSink, RouterSink};
+    ///
+    /// let left = Vec::<usize>::new();
+    ///
+    /// let router: RouterSink<_, NullSink<String>> = RouterSink::left_only(left);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    pub fn left_only(left_sink: A) -> RouterSink<A, NullSink<T>> {
+        RouterSink::new(left_sink, NullSink::new())
+    }
+}
+
+impl<T, B> RouterSink<NullSink<T>, B> {
+    /// Create a new RouterSink delivering right routed items to the given
+    /// sink and discarding left routed items of type `T`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{NullSink, RouterSink};
+    ///
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router: RouterSink<NullSink<String>, _> = RouterSink::right_only(right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    pub fn right_only(right_sink: B) -> RouterSink<NullSink<T>, B> {
+        RouterSink::new(NullSink::new(), right_sink)
+    }
+}
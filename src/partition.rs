@@ -0,0 +1,101 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+
+/// A sink routing items to one of two sinks by comparing a key of the
+/// item against a pivot
+///
+/// Items whose key is below the pivot are routed left, all others are
+/// routed right. This includes items whose key is equal to the pivot as
+/// well as keys that cannot be compared with the pivot at all.
+pub struct PartitionRouterSink<A, B, F, K> {
+    /// The router the partitioned items are sent to
+    router: RouterSink<A, B>,
+    /// Extracts the key to partition by from an item
+    key_fn: F,
+    /// The key splitting the left from the right route
+    pivot: K,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new PartitionRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let older = Vec::<(u64, &str)>::new();
+    /// let newer = Vec::<(u64, &str)>::new();
+    ///
+    /// let router = RouterSink::partition_by(older, newer, |x: &(u64, &str)| x.0, 1500);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if the key is below the pivot
+    /// - `right_sink`: The sink chosen by the router if the key is at or above the pivot
+    /// - `key_fn`: Extracts the key to partition by from an item
+    /// - `pivot`: The key splitting the left from the right route
+    pub fn partition_by<K, F>(left_sink: A,
+                              right_sink: B,
+                              key_fn: F,
+                              pivot: K)
+                              -> PartitionRouterSink<A, B, F, K> {
+        PartitionRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            key_fn,
+            pivot,
+        }
+    }
+}
+
+impl<A, B, F, K> PartitionRouterSink<A, B, F, K> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, F, K> Sink for PartitionRouterSink<A, B, F, K>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>,
+          F: Fn(&A::SinkItem) -> K,
+          K: PartialOrd
+{
+    type SinkItem = A::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let route = if (self.key_fn)(&item) < self.pivot {
+            Route::Left(item)
+        } else {
+            Route::Right(item)
+        };
+
+        self.router
+            .start_send(route)
+            .map(|x| x.map(Route::into_inner))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,87 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{RouterSink, Side};
+
+/// A sink routing items to one of two sinks, always flushing the sink of
+/// the high priority side to completion before the other one
+///
+/// Unlike the flush bias of `RouterSink`, which only decides the order in
+/// which both sinks are flushed, the low priority sink is not flushed at all
+/// while the high priority sink still has pending items. The low priority
+/// sink can thus be starved for as long as the high priority side is busy.
+pub struct PriorityRouterSink<A, B> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// The side whose sink is flushed first
+    high: Side,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new PriorityRouterSink for the two given sinks, where the
+    /// sink for the given side has high priority
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{RouterSink, Side};
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::with_priority(left, right, Side::Left);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    /// - `high`: The side whose sink is flushed to completion first
+    pub fn with_priority(left_sink: A, right_sink: B, high: Side) -> PriorityRouterSink<A, B> {
+        PriorityRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            high,
+        }
+    }
+}
+
+impl<A, B> PriorityRouterSink<A, B> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B> Sink for PriorityRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.router.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        try_ready!(self.router.poll_complete_side(self.high));
+        self.router.poll_complete_side(self.high.opposite())
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        try_ready!(self.router.poll_complete_side(self.high));
+        self.router.close()
+    }
+}
@@ -0,0 +1,42 @@
+use futures::{Async, Poll, Sink};
+use futures::sync::mpsc::{Sender, UnboundedSender};
+
+/// A sink able to report whether it can accept an item without being
+/// sent one
+///
+/// This crate implements it for `Vec`, the `mpsc` senders, `NullSink`,
+/// `SharedSink` and `RouterSink`. Other sinks need an implementation of
+/// their own, which may be empty for sinks that cannot tell. Due to the
+/// orphan rule, a sink type defined in another crate has to be wrapped in
+/// a local newtype to implement it.
+pub trait PollReady: Sink {
+    /// Poll whether the sink can currently accept an item
+    ///
+    /// The default implementation always reports `Ready`. This is meant for
+    /// sinks that cannot tell without actually being sent an item, in which
+    /// case `start_send` may still return `NotReady`.
+    ///
+    /// # Return value
+    ///
+    /// `Ready` if the sink can accept an item, `NotReady` otherwise
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        Ok(Async::Ready(()))
+    }
+}
+
+impl<T> PollReady for Vec<T> {}
+
+impl<T> PollReady for Sender<T> {
+    /// Poll whether the channel has capacity for another item
+    ///
+    /// A disconnected channel reports `Ready`, as its error cannot carry an
+    /// item here. The next `start_send` fails, handing the item back.
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        match Sender::poll_ready(self) {
+            Ok(x) => Ok(x),
+            Err(_) => Ok(Async::Ready(())),
+        }
+    }
+}
+
+impl<T> PollReady for UnboundedSender<T> {}
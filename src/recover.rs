@@ -0,0 +1,136 @@
+use error::RouterSinkError;
+use futures::{Async, AsyncSink, Poll, Sink, StartSend};
+use router_sink::RouterSink;
+use std::mem;
+
+/// A sink routing items to one of two sinks, collecting the errors of the
+/// inner sinks instead of failing
+///
+/// An error of an inner sink is stored and otherwise treated as if the
+/// operation had succeeded, so a stream forwarded into the router runs to
+/// completion. The collected errors are taken with `take_errors`. Note that
+/// an item whose `start_send` failed is dropped, as are any items the
+/// failing sink had not flushed yet.
+pub struct RecoveringRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// The errors collected since they were last taken
+    errors: Vec<RouterSinkError<A::SinkError, B::SinkError>>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Adapt the router to collect the errors of the inner sinks instead of
+    /// failing
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::new(left, right).recover();
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The router collecting the errors of the inner sinks
+    pub fn recover(self) -> RecoveringRouterSink<A, B> {
+        RecoveringRouterSink {
+            router: self,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<A, B> RecoveringRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Take the errors collected so far
+    ///
+    /// # Return value
+    ///
+    /// The errors of the inner sinks in the order they occurred
+    pub fn take_errors(&mut self) -> Vec<RouterSinkError<A::SinkError, B::SinkError>> {
+        mem::take(&mut self.errors)
+    }
+
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+
+    /// Collect the errors of the given results
+    ///
+    /// # Return value
+    ///
+    /// `Ready` if both results are ready or failed, `NotReady` otherwise
+    fn recover(&mut self,
+               left: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>,
+               right: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>)
+               -> Poll<(), ()> {
+        let mut ready = true;
+        for result in [left, right] {
+            match result {
+                Ok(Async::Ready(())) => {}
+                Ok(Async::NotReady) => ready = false,
+                Err(e) => self.errors.push(e),
+            }
+        }
+
+        if ready {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl<A, B> Sink for RecoveringRouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = ();
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, ()> {
+        match self.router.start_send(item) {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                self.errors.push(e);
+                Ok(AsyncSink::Ready)
+            }
+        }
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), ()> {
+        let (left, right) = self.router.poll_complete_each();
+        self.recover(left, right)
+    }
+
+    fn close(&mut self) -> Poll<(), ()> {
+        let (left, right) = self.router.close_each();
+        self.recover(left, right)
+    }
+}
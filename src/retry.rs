@@ -0,0 +1,126 @@
+use error::RouterSinkError;
+use futures::{Async, Poll, Sink, StartSend};
+use futures::task;
+use router_sink::RouterSink;
+
+/// The number of consecutive retries of a failed flush, by default
+const DEFAULT_MAX_RETRIES: usize = 3;
+
+/// A sink routing items to one of two sinks, retrying flushes that failed
+/// with a retryable error
+///
+/// If `poll_complete` fails with an error the policy deems retryable, the
+/// error is swallowed and `NotReady` is returned after scheduling the
+/// current task, if any, to be polled again. Once the maximum number of
+/// retries is exhausted, or the error is not retryable, it is propagated
+/// as usual, so with the default of three retries the fourth consecutive
+/// failure is returned. The retries are only reset once a flush succeeds,
+/// so flushes returning `NotReady` in between failed ones do not extend
+/// them. Errors in `start_send` are never retried.
+pub struct RetryRouterSink<A, B, P> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// Decides whether an error is retryable
+    policy: P,
+    /// The maximum number of consecutive retries
+    max_retries: usize,
+    /// The number of consecutive retries so far
+    retries: usize,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new RetryRouterSink for the two given sinks, retrying a
+    /// failed flush up to three times in a row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{RouterSink, RouterSinkError};
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::new_retry(left, right, |_: &RouterSinkError<(), ()>| true);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    /// - `policy`: Decides whether an error is retryable
+    pub fn new_retry<P>(left_sink: A, right_sink: B, policy: P) -> RetryRouterSink<A, B, P> {
+        RetryRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            policy,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retries: 0,
+        }
+    }
+}
+
+impl<A, B, P> RetryRouterSink<A, B, P> {
+    /// Set the maximum number of consecutive retries of a failed flush
+    ///
+    /// The failure following the last retry is propagated, so the error is
+    /// returned on the `max_retries + 1`th consecutive failed flush.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_retries`: The maximum number of consecutive retries
+    pub fn set_max_retries(&mut self, max_retries: usize) {
+        self.max_retries = max_retries;
+    }
+
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, P> Sink for RetryRouterSink<A, B, P>
+    where A: Sink,
+          B: Sink,
+          P: Fn(&RouterSinkError<A::SinkError, B::SinkError>) -> bool
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.router.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        match self.router.poll_complete() {
+            Err(ref e) if self.retries < self.max_retries && (self.policy)(e) => {
+                self.retries += 1;
+                if task::is_in_task() {
+                    task::current().notify();
+                }
+                Ok(Async::NotReady)
+            }
+            x => {
+                if let Ok(Async::Ready(())) = x {
+                    self.retries = 0;
+                }
+                x
+            }
+        }
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
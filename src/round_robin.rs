@@ -0,0 +1,85 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink, Side};
+
+/// A sink alternating items between two sinks, starting with the left
+///
+/// The router only moves on to the other sink once an item has been
+/// accepted, so an item handed back as `NotReady` is retried on the same
+/// sink.
+pub struct RoundRobinRouterSink<A, B> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// The side the next item is routed to
+    next: Side,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new RoundRobinRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::round_robin(left, right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink receiving the first, third, fifth, ... item
+    /// - `right_sink`: The sink receiving the second, fourth, sixth, ... item
+    pub fn round_robin(left_sink: A, right_sink: B) -> RoundRobinRouterSink<A, B> {
+        RoundRobinRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            next: Side::Left,
+        }
+    }
+}
+
+impl<A, B> RoundRobinRouterSink<A, B> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B> Sink for RoundRobinRouterSink<A, B>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>
+{
+    type SinkItem = A::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let result = self.router.start_send(Route::from_side(self.next, item))?;
+        if result.is_ready() {
+            self.next = self.next.opposite();
+        }
+        Ok(result.map(Route::into_inner))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -1,5 +1,8 @@
 use error::RouterSinkError;
 use futures::{Async, AsyncSink, Poll, Sink, StartSend};
+use ready::PollReady;
+use futures::sink;
+use std::mem;
 
 /// Marker to decide which route the item has to take
 pub enum Route<A, B> {
@@ -9,26 +12,284 @@ pub enum Route<A, B> {
     Right(B),
 }
 
+/// One of the two routes an item can take
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The left route
+    Left,
+    /// The right route
+    Right,
+}
+
+/// Whether the router accepted an item, see `RouterSink::try_route`
+pub enum RouteAccepted<A, B> {
+    /// The item was accepted by the sink for its route
+    Accepted,
+    /// The sink for its route was not ready, the item is handed back
+    Rejected(Route<A, B>),
+}
+
+/// The order in which `poll_complete` flushes the two sinks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bias {
+    /// Always flush the left sink first
+    Left,
+    /// Always flush the right sink first
+    Right,
+    /// Alternate which sink is flushed first on each call
+    RoundRobin,
+}
+
+impl Side {
+    /// The other side
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::Side;
+    /// assert_eq!(Side::Left.opposite(), Side::Right);
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// `Right` for `Left` and `Left` for `Right`
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+impl<A, B> Route<A, B> {
+    /// The side the item is routed to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, Side};
+    /// let route = Route::Left::<u32, &str>(23);
+    /// assert_eq!(route.side(), Side::Left);
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// `Left` for a left route item and `Right` for a right route item
+    pub fn side(&self) -> Side {
+        match *self {
+            Route::Left(_) => Side::Left,
+            Route::Right(_) => Side::Right,
+        }
+    }
+
+    /// Borrow the item without consuming the route
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::Route;
+    /// let route = Route::Left::<String, u32>("item".to_string());
+    /// if let Route::Left(x) = route.as_ref() {
+    ///     assert_eq!(x, "item");
+    /// }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A route of the same variant holding a reference to the item
+    pub fn as_ref(&self) -> Route<&A, &B> {
+        match *self {
+            Route::Left(ref x) => Route::Left(x),
+            Route::Right(ref x) => Route::Right(x),
+        }
+    }
+
+    /// Mutably borrow the item without consuming the route
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::Route;
+    /// let mut route = Route::Right::<String, u32>(23);
+    /// if let Route::Right(x) = route.as_mut() {
+    ///     *x = 42;
+    /// }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A route of the same variant holding a mutable reference to the item
+    pub fn as_mut(&mut self) -> Route<&mut A, &mut B> {
+        match *self {
+            Route::Left(ref mut x) => Route::Left(x),
+            Route::Right(ref mut x) => Route::Right(x),
+        }
+    }
+}
+
+impl<T> Route<T, T> {
+    /// Tag an item with the route for the given side
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, Side};
+    /// let route = Route::from_side(Side::Right, 42);
+    /// assert_eq!(route.side(), Side::Right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `side`: The side the item is routed to
+    /// - `item`: The item to route
+    ///
+    /// # Return value
+    ///
+    /// The item tagged with the route for the given side
+    pub fn from_side(side: Side, item: T) -> Route<T, T> {
+        match side {
+            Side::Left => Route::Left(item),
+            Side::Right => Route::Right(item),
+        }
+    }
+
+    /// Tag an item with the left route if it matches the predicate, or with
+    /// the right route otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, Side};
+    /// let route = Route::branch(42, |x| x % 2 == 0);
+    /// assert_eq!(route.side(), Side::Left);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to route
+    /// - `pred`: Decides whether the item is routed to the left
+    ///
+    /// # Return value
+    ///
+    /// The item tagged with the route chosen by the predicate
+    pub fn branch<F>(item: T, pred: F) -> Route<T, T>
+        where F: FnOnce(&T) -> bool
+    {
+        if pred(&item) {
+            Route::Left(item)
+        } else {
+            Route::Right(item)
+        }
+    }
+
+    /// Split the route into its side and the item
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, Side};
+    /// let (side, item) = Route::Left(23).into_parts();
+    /// assert_eq!((side, item), (Side::Left, 23));
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the side the item is routed to and the item
+    pub fn into_parts(self) -> (Side, T) {
+        match self {
+            Route::Left(x) => (Side::Left, x),
+            Route::Right(x) => (Side::Right, x),
+        }
+    }
+
+    /// Join a side and an item into a route, the inverse of `into_parts`
+    ///
+    /// This is the same as `from_side`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, Side};
+    /// let (side, item) = Route::Right(42).into_parts();
+    /// let route = Route::from_parts(side.opposite(), item);
+    /// assert_eq!(route.into_parts(), (Side::Left, 42));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `side`: The side the item is routed to
+    /// - `item`: The item to route
+    ///
+    /// # Return value
+    ///
+    /// The item tagged with the route for the given side
+    pub fn from_parts(side: Side, item: T) -> Route<T, T> {
+        Route::from_side(side, item)
+    }
+
+    /// Strip the route from an item whose routes share the same type
+    pub(crate) fn into_inner(self) -> T {
+        match self {
+            Route::Left(x) | Route::Right(x) => x,
+        }
+    }
+}
+
+/// The point at which an item routed to a sink is counted as a success
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CountPoint {
+    /// Count an item as soon as the sink accepted it in `start_send`
+    Accepted,
+    /// Count an item only once the sink confirmed it flushed in
+    /// `poll_complete`
+    Flushed,
+}
+
+/// Bookkeeping of the items routed to one of the sinks
+#[derive(Clone, Copy, Default)]
+struct Counts {
+    /// The number of items counted as a success
+    successes: u64,
+    /// The number of errors returned by the sink
+    errors: u64,
+    /// The number of items accepted but not yet confirmed flushed
+    in_flight: usize,
+}
+
 /// A sink capable of routing incoming items to one of two sinks
+///
+/// The router is `Send`, `Sync` and `Unpin` whenever both inner sinks are.
+///
+/// # Ordering
+///
+/// Items routed to the same side reach its sink in the order they were
+/// sent. As the two sides are independent sinks, no order is defined
+/// between items routed to different sides. If the global order has to be
+/// observable downstream, see `SequencedRouterSink`.
 pub struct RouterSink<A, B> {
     /// The sink for the left route
     left_sink: A,
     /// The sink for the right route
     right_sink: B,
-}
-
-/// Poll the given sink and map the error to an appropriate type with
-/// the given conversion function
-fn poll_complete<S, F, E>(sink: &mut S, f: F) -> Poll<(), E>
-    where S: Sink,
-          F: Fn(S::SinkError) -> E
-{
-    match sink.poll_complete() {
-            Ok(Async::Ready(x)) => Ok(Async::Ready(x)),
-            Ok(Async::NotReady) => Ok(Async::NotReady),
-            Err(e) => Err(e),
-        }
-        .map_err(f)
+    /// The order in which the sinks are flushed
+    bias: Bias,
+    /// The sink flushed first on the next round robin flush
+    next_first: Side,
+    /// The point at which routed items are counted as a success
+    count_on: CountPoint,
+    /// The bookkeeping of the items routed to the left sink
+    left_counts: Counts,
+    /// The bookkeeping of the items routed to the right sink
+    right_counts: Counts,
+    /// Whether the left sink is closed and rejects further items
+    left_sealed: bool,
+    /// Whether the right sink is closed and rejects further items
+    right_sealed: bool,
+    /// The sequence numbers and sides of the items sent with
+    /// `start_send_tracked` since the receipts were last taken
+    receipts: Vec<(u64, Side)>,
+    /// The sequence number of the next tracked item
+    next_seq: u64,
 }
 
 /// Start sending an item on the given sink, map the item back to its route
@@ -69,6 +330,15 @@ impl<A, B> RouterSink<A, B> {
         RouterSink {
             left_sink,
             right_sink,
+            bias: Bias::Left,
+            next_first: Side::Left,
+            count_on: CountPoint::Accepted,
+            left_counts: Counts::default(),
+            right_counts: Counts::default(),
+            left_sealed: false,
+            right_sealed: false,
+            receipts: Vec::new(),
+            next_seq: 0,
         }
     }
 
@@ -118,8 +388,8 @@ impl<A, B> RouterSink<A, B> {
     /// # use futures_router_sink::RouterSink;
     /// # let left = Vec::<usize>::new();
     /// # let right = Vec::<usize>::new();
-    /// let router = RouterSink::new(left, right);
-    /// let right = router.left_mut();
+    /// let mut router = RouterSink::new(left, right);
+    /// let left = router.left_mut();
     /// ```
     ///
     /// # Return value
@@ -137,8 +407,8 @@ impl<A, B> RouterSink<A, B> {
     /// # use futures_router_sink::RouterSink;
     /// # let left = Vec::<usize>::new();
     /// # let right = Vec::<usize>::new();
-    /// let router = RouterSink::new(left, right);
-    /// let right = router.left_mut();
+    /// let mut router = RouterSink::new(left, right);
+    /// let right = router.right_mut();
     /// ```
     ///
     /// # Return value
@@ -147,6 +417,719 @@ impl<A, B> RouterSink<A, B> {
     pub fn right_mut(&mut self) -> &mut B {
         &mut self.right_sink
     }
+
+    /// Mutable access to both inner sinks at the same time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::RouterSink;
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// let (left, right) = router.both_mut();
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of mutable references to the inner left and right route sinks
+    pub fn both_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.left_sink, &mut self.right_sink)
+    }
+
+    /// Replace both inner sinks at once, e.g. for a coordinated failover
+    ///
+    /// The old sinks are handed back as they are, so the caller should
+    /// flush them first if none of their items may be lost. Items pending
+    /// in the old sinks no longer count as pending, and sealed sides are
+    /// unsealed, while the success and error counts are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::RouterSink;
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// let (old_left, old_right) = router.replace_both(Vec::new(), Vec::new());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `new_left`: The new sink for the left route
+    /// - `new_right`: The new sink for the right route
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the old left and right route sinks
+    pub fn replace_both(&mut self, new_left: A, new_right: B) -> (A, B) {
+        self.left_counts.in_flight = 0;
+        self.right_counts.in_flight = 0;
+        self.left_sealed = false;
+        self.right_sealed = false;
+        let old_left = mem::replace(&mut self.left_sink, new_left);
+        let old_right = mem::replace(&mut self.right_sink, new_right);
+        (old_left, old_right)
+    }
+
+    /// Set the order in which `poll_complete` flushes the two sinks
+    ///
+    /// By default the left sink is always flushed first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Bias, RouterSink};
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// router.set_flush_bias(Bias::RoundRobin);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `bias`: The order in which the sinks are flushed
+    pub fn set_flush_bias(&mut self, bias: Bias) {
+        self.bias = bias;
+    }
+
+    /// The number of errors each inner sink has returned so far
+    ///
+    /// Errors are counted whenever an inner sink fails in `start_send`,
+    /// `poll_complete` or `close`. If both sinks fail in the same call,
+    /// both counts are incremented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::RouterSink;
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let router = RouterSink::new(left, right);
+    /// assert_eq!(router.error_counts(), (0, 0));
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the error counts of the left and right route sinks
+    pub fn error_counts(&self) -> (u64, u64) {
+        (self.left_counts.errors, self.right_counts.errors)
+    }
+
+    /// Set the point at which routed items are counted as a success
+    ///
+    /// By default items are counted as soon as they are accepted. Since
+    /// items already accepted are not recounted, this should be set before
+    /// any item is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{CountPoint, RouterSink};
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// router.set_count_point(CountPoint::Flushed);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `count_on`: The point at which routed items are counted as a success
+    pub fn set_count_point(&mut self, count_on: CountPoint) {
+        self.count_on = count_on;
+    }
+
+    /// The number of items each inner sink has successfully received so far
+    ///
+    /// Whether an item counts once accepted or only once flushed depends on
+    /// the count point, see `set_count_point`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::RouterSink;
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let router = RouterSink::new(left, right);
+    /// assert_eq!(router.success_counts(), (0, 0));
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the success counts of the left and right route sinks
+    pub fn success_counts(&self) -> (u64, u64) {
+        (self.left_counts.successes, self.right_counts.successes)
+    }
+
+    /// The number of items each inner sink has accepted but not yet flushed
+    ///
+    /// Items are pending from the moment an inner sink accepts them until
+    /// flushing that sink returns `Ready`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # use futures::Sink;
+    /// # use futures_router_sink::{Route, RouterSink};
+    /// # fn main() {
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// router.start_send(Route::Left(1)).unwrap();
+    /// assert_eq!(router.pending_counts(), (1, 0));
+    ///
+    /// router.poll_complete().unwrap();
+    /// assert_eq!(router.pending_counts(), (0, 0));
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the pending item counts of the left and right route sinks
+    pub fn pending_counts(&self) -> (usize, usize) {
+        (self.left_counts.in_flight, self.right_counts.in_flight)
+    }
+
+    /// Take the receipts of the items sent with `start_send_tracked` so far
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{Route, RouterSink, Side};
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// router.start_send_tracked(Route::Right(42)).unwrap();
+    ///
+    /// assert_eq!(router.take_receipts(), vec![(0, Side::Right)]);
+    /// assert!(router.take_receipts().is_empty());
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// The sequence number and side of each accepted item, in the order
+    /// the items were accepted
+    pub fn take_receipts(&mut self) -> Vec<(u64, Side)> {
+        mem::take(&mut self.receipts)
+    }
+
+    /// Whether the sink for the given side is sealed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use futures_router_sink::{RouterSink, Side};
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let router = RouterSink::new(left, right);
+    /// assert!(!router.is_sealed(Side::Left));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `side`: The side whose sink is checked
+    ///
+    /// # Return value
+    ///
+    /// `true` once sealing the sink for the given side completed
+    pub fn is_sealed(&self, side: Side) -> bool {
+        match side {
+            Side::Left => self.left_sealed,
+            Side::Right => self.right_sealed,
+        }
+    }
+
+    /// The bookkeeping of the items routed to the given side
+    fn counts_mut(&mut self, side: Side) -> &mut Counts {
+        match side {
+            Side::Left => &mut self.left_counts,
+            Side::Right => &mut self.right_counts,
+        }
+    }
+
+    /// Record an item accepted by the sink for the given side
+    fn accepted(&mut self, side: Side) {
+        let count_on = self.count_on;
+        let counts = self.counts_mut(side);
+        counts.in_flight += 1;
+        if count_on == CountPoint::Accepted {
+            counts.successes += 1;
+        }
+    }
+
+    /// Record the result of polling the sink for the given side
+    fn polled<T, E>(&mut self, side: Side, result: &Poll<T, E>) {
+        let count_on = self.count_on;
+        let counts = self.counts_mut(side);
+        match *result {
+            Ok(Async::Ready(_)) => {
+                if count_on == CountPoint::Flushed {
+                    counts.successes += counts.in_flight as u64;
+                }
+                counts.in_flight = 0;
+            }
+            Ok(Async::NotReady) => {}
+            Err(_) => counts.errors += 1,
+        }
+    }
+
+    /// Decide which sink is flushed first, advancing the round robin toggle
+    fn flush_first(&mut self) -> Side {
+        match self.bias {
+            Bias::Left => Side::Left,
+            Bias::Right => Side::Right,
+            Bias::RoundRobin => {
+                let first = self.next_first;
+                self.next_first = first.opposite();
+                first
+            }
+        }
+    }
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Send a single routed item, returning a future that resolves to
+    /// the router once the item has been sent and flushed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Future;
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    ///
+    /// let router = router
+    ///     .send_route(Route::Left(1))
+    ///     .and_then(|router| router.send_route(Route::Right(2)))
+    ///     .wait()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(router.left(), &vec![1]);
+    /// assert_eq!(router.right(), &vec![2]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to send, tagged with the route it has to take
+    ///
+    /// # Return value
+    ///
+    /// A future resolving to the router after the item has been flushed
+    pub fn send_route(self, item: Route<A::SinkItem, B::SinkItem>) -> sink::Send<Self> {
+        self.send(item)
+    }
+
+    /// Try to route an item, handing it back if the sink for its route is
+    /// not ready to accept it
+    ///
+    /// This is a friendlier alternative to `start_send`, translating its
+    /// `AsyncSink` into a `RouteAccepted`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{Route, RouteAccepted, RouterSink};
+    ///
+    /// let mut router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    ///
+    /// match router.try_route(Route::Left(23)) {
+    ///     Ok(RouteAccepted::Accepted) => {}
+    ///     Ok(RouteAccepted::Rejected(_)) => println!("try again later"),
+    ///     Err(_) => println!("sink failed"),
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to route, tagged with the route it has to take
+    ///
+    /// # Return value
+    ///
+    /// `Accepted` if the item was accepted, `Rejected` with the item if the
+    /// sink was not ready, or the error of the sink
+    pub fn try_route(&mut self,
+                     item: Route<A::SinkItem, B::SinkItem>)
+                     -> Result<RouteAccepted<A::SinkItem, B::SinkItem>, <Self as Sink>::SinkError> {
+        match self.start_send(item)? {
+            AsyncSink::Ready => Ok(RouteAccepted::Accepted),
+            AsyncSink::NotReady(x) => Ok(RouteAccepted::Rejected(x)),
+        }
+    }
+
+    /// Start sending an item like `start_send`, recording a receipt of the
+    /// side it was routed to once it is accepted
+    ///
+    /// Each accepted item is assigned the next of a monotonic sequence of
+    /// numbers. No receipt is recorded for an item that is handed back
+    /// because its sink is not ready, so retrying it later yields exactly
+    /// one receipt. The receipts are collected with `take_receipts`.
+    ///
+    /// # Arguments
+    ///
+    /// - `item`: The item to send, tagged with the route it has to take
+    ///
+    /// # Return value
+    ///
+    /// The result of `start_send`
+    pub fn start_send_tracked(&mut self,
+                              item: Route<A::SinkItem, B::SinkItem>)
+                              -> StartSend<Route<A::SinkItem, B::SinkItem>, <Self as Sink>::SinkError> {
+        let side = item.side();
+        let result = self.start_send(item)?;
+        if result.is_ready() {
+            self.receipts.push((self.next_seq, side));
+            self.next_seq += 1;
+        }
+        Ok(result)
+    }
+
+    /// Probe whether both sinks are currently healthy, without sending
+    /// any data
+    ///
+    /// Both sinks are flushed, and the probe is `Ready` only if neither
+    /// has pending items nor failed. Unlike `poll_complete` this is meant
+    /// as a liveness probe, e.g. for a readiness endpoint, and should be
+    /// cheap for idle sinks. The flush order does not follow the bias.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Async;
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let mut router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    ///
+    /// assert_eq!(router.poll_health().ok(), Some(Async::Ready(())));
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// `Ready` if both sinks are flushed, `NotReady` if either still has
+    /// pending items, or the error of the failing side
+    pub fn poll_health(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        self.poll_both(Side::Left, A::poll_complete, B::poll_complete)
+    }
+
+    /// Flush both sinks, reporting how many items each finished flushing
+    ///
+    /// Behaves like `poll_complete`. Items routed to a side are confirmed
+    /// once flushing that sink returns `Ready`, so a side's count is either
+    /// zero or all its pending items, see `pending_counts`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # use futures::Sink;
+    /// # use futures_router_sink::{Route, RouterSink};
+    /// # fn main() {
+    /// # let left = Vec::<usize>::new();
+    /// # let right = Vec::<usize>::new();
+    /// let mut router = RouterSink::new(left, right);
+    /// router.start_send(Route::Left(1)).unwrap();
+    /// router.start_send(Route::Right(2)).unwrap();
+    ///
+    /// assert_eq!(router.flush_progress().ok(), Some((1, 1)));
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the number of items the left and right route sinks
+    /// finished flushing during this call, or the error of the first
+    /// failing side
+    pub fn flush_progress(&mut self) -> Result<(usize, usize), <Self as Sink>::SinkError> {
+        let (left, right) = self.pending_counts();
+        self.poll_complete()?;
+        let (left_pending, right_pending) = self.pending_counts();
+        Ok((left - left_pending, right - right_pending))
+    }
+
+    /// Drain both sinks, interleaving flushes until neither can make
+    /// progress
+    ///
+    /// Intended for a draining phase at shutdown. Unlike `poll_complete`,
+    /// whenever one sink finishes flushing, the sink still pending is
+    /// flushed again, in case it can now make progress, e.g. because both
+    /// share the same downstream capacity. `NotReady` is only returned once
+    /// a full round of flushes finished neither sink.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Async;
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let mut router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    ///
+    /// assert_eq!(router.poll_drain().ok(), Some(Async::Ready(())));
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// `Ready` once both sinks are flushed, `NotReady` if neither can make
+    /// progress, or the error of the first failing side
+    pub fn poll_drain(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        let mut left_done = false;
+        let mut right_done = false;
+
+        loop {
+            let mut progress = false;
+
+            if !left_done {
+                left_done = self.poll_complete_side(Side::Left)?.is_ready();
+                progress |= left_done;
+            }
+            if !right_done {
+                right_done = self.poll_complete_side(Side::Right)?.is_ready();
+                progress |= right_done;
+            }
+
+            if left_done && right_done {
+                return Ok(Async::Ready(()));
+            }
+            if !progress {
+                return Ok(Async::NotReady);
+            }
+        }
+    }
+
+    /// Close the left sink and seal it, so any further item routed to the
+    /// left fails with `RouterSinkError::SideSealed`
+    ///
+    /// Items routed to the right are unaffected. Once sealed, the left sink
+    /// is neither flushed nor closed by the router anymore.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Async, Sink};
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let mut router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    /// assert_eq!(router.seal_left().ok(), Some(Async::Ready(())));
+    ///
+    /// assert!(router.start_send(Route::Left(23)).is_err());
+    /// assert!(router.start_send(Route::Right(42)).is_ok());
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// `Ready` once the left sink is closed and sealed, `NotReady` if it is
+    /// still closing, or its error
+    pub fn seal_left(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        if !self.left_sealed {
+            let result = self.left_sink.close();
+            self.polled(Side::Left, &result);
+            try_ready!(result.map_err(RouterSinkError::Left));
+            self.left_sealed = true;
+        }
+        Ok(Async::Ready(()))
+    }
+
+    /// Close the right sink and seal it, so any further item routed to the
+    /// right fails with `RouterSinkError::SideSealed`
+    ///
+    /// Items routed to the left are unaffected. Once sealed, the right
+    /// sink is neither flushed nor closed by the router anymore.
+    ///
+    /// # Return value
+    ///
+    /// `Ready` once the right sink is closed and sealed, `NotReady` if it
+    /// is still closing, or its error
+    pub fn seal_right(&mut self) -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        if !self.right_sealed {
+            let result = self.right_sink.close();
+            self.polled(Side::Right, &result);
+            try_ready!(result.map_err(RouterSinkError::Right));
+            self.right_sealed = true;
+        }
+        Ok(Async::Ready(()))
+    }
+
+    /// Flush both sinks in the order given by the bias, counting errors,
+    /// without combining the results
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the results of flushing the left and right route sinks
+    pub(crate) fn poll_complete_each(&mut self) -> (Poll<(), <Self as Sink>::SinkError>,
+                                                    Poll<(), <Self as Sink>::SinkError>) {
+        let first = self.flush_first();
+        self.poll_each(first, A::poll_complete, B::poll_complete)
+    }
+
+    /// Flush only the sink for the given side, counting errors
+    pub(crate) fn poll_complete_side(&mut self, side: Side) -> Poll<(), <Self as Sink>::SinkError> {
+        if self.is_sealed(side) {
+            return Ok(Async::Ready(()));
+        }
+
+        match side {
+            Side::Left => {
+                let result = self.left_sink.poll_complete();
+                self.polled(side, &result);
+                result.map_err(RouterSinkError::Left)
+            }
+            Side::Right => {
+                let result = self.right_sink.poll_complete();
+                self.polled(side, &result);
+                result.map_err(RouterSinkError::Right)
+            }
+        }
+    }
+
+    /// Close both sinks in the order given by the bias, counting errors,
+    /// without combining the results
+    ///
+    /// # Return value
+    ///
+    /// A tuple of the results of closing the left and right route sinks
+    pub(crate) fn close_each(&mut self) -> (Poll<(), <Self as Sink>::SinkError>,
+                                            Poll<(), <Self as Sink>::SinkError>) {
+        let first = self.flush_first();
+        self.poll_each(first, A::close, B::close)
+    }
+
+    /// Poll both sinks with the given functions, starting with the given
+    /// side, counting errors
+    fn poll_each<F, G>(&mut self,
+                       first: Side,
+                       f: F,
+                       g: G)
+                       -> (Poll<(), <Self as Sink>::SinkError>, Poll<(), <Self as Sink>::SinkError>)
+        where F: FnOnce(&mut A) -> Poll<(), A::SinkError>,
+              G: FnOnce(&mut B) -> Poll<(), B::SinkError>
+    {
+        let left_sealed = self.left_sealed;
+        let right_sealed = self.right_sealed;
+        // A sealed sink is closed already and counts as flushed
+        let f = |sink: &mut A| if left_sealed { Ok(Async::Ready(())) } else { f(sink) };
+        let g = |sink: &mut B| if right_sealed { Ok(Async::Ready(())) } else { g(sink) };
+
+        let (left, right) = if first == Side::Left {
+            let left = f(&mut self.left_sink);
+            (left, g(&mut self.right_sink))
+        } else {
+            let right = g(&mut self.right_sink);
+            (f(&mut self.left_sink), right)
+        };
+
+        self.polled(Side::Left, &left);
+        self.polled(Side::Right, &right);
+
+        (left.map_err(RouterSinkError::Left), right.map_err(RouterSinkError::Right))
+    }
+
+    /// Poll both sinks with the given functions, starting with the given
+    /// side, counting errors and combining the results
+    fn poll_both<F, G>(&mut self,
+                       first: Side,
+                       f: F,
+                       g: G)
+                       -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>>
+        where F: FnOnce(&mut A) -> Poll<(), A::SinkError>,
+              G: FnOnce(&mut B) -> Poll<(), B::SinkError>
+    {
+        match self.poll_each(first, f, g) {
+            (Ok(Async::Ready(())), Ok(Async::Ready(()))) => Ok(Async::Ready(())),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+            (Ok(Async::NotReady), _) |
+            (_, Ok(Async::NotReady)) => Ok(Async::NotReady),
+        }
+    }
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: PollReady,
+          B: PollReady
+{
+    /// Poll whether the sink for the given side can currently accept an
+    /// item, without sending one
+    ///
+    /// Sinks that cannot tell report `Ready`, see `PollReady::poll_ready`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Async;
+    /// use futures_router_sink::{RouterSink, Side};
+    ///
+    /// let mut router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    ///
+    /// assert_eq!(router.poll_ready(Side::Left).ok(), Some(Async::Ready(())));
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `side`: The side whose sink is probed
+    ///
+    /// # Return value
+    ///
+    /// `Ready` if the sink for the given side can accept an item,
+    /// `NotReady` otherwise
+    pub fn poll_ready(&mut self,
+                      side: Side)
+                      -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        if self.is_sealed(side) {
+            return Err(RouterSinkError::SideSealed(side));
+        }
+        match side {
+            Side::Left => self.left_sink.poll_ready().map_err(RouterSinkError::Left),
+            Side::Right => self.right_sink.poll_ready().map_err(RouterSinkError::Right),
+        }
+    }
+}
+
+impl<A, B> PollReady for RouterSink<A, B>
+    where A: PollReady,
+          B: PollReady
+{
+    /// Poll whether the sinks for both sides can currently accept an item
+    ///
+    /// Sealed sides are not probed, as an item routed to them fails rather
+    /// than being handed back.
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        let mut ready = true;
+        for side in [Side::Left, Side::Right] {
+            if !self.is_sealed(side) {
+                ready &= RouterSink::poll_ready(self, side)?.is_ready();
+            }
+        }
+
+        if ready {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
 }
 
 impl<A, B> Sink for RouterSink<A, B>
@@ -157,7 +1140,12 @@ impl<A, B> Sink for RouterSink<A, B>
     type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
 
     fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
-        match item {
+        let side = item.side();
+        if self.is_sealed(side) {
+            return Err(RouterSinkError::SideSealed(side));
+        }
+
+        let result = match item {
             Route::Left(x) => {
                 start_send(&mut self.left_sink, x, RouterSinkError::Left, Route::Left)
             }
@@ -167,17 +1155,33 @@ impl<A, B> Sink for RouterSink<A, B>
                            RouterSinkError::Right,
                            Route::Right)
             }
+        };
+
+        match result {
+            Ok(AsyncSink::Ready) => self.accepted(side),
+            Ok(AsyncSink::NotReady(_)) => {}
+            Err(_) => self.counts_mut(side).errors += 1,
         }
+        result
     }
 
     fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
-        match (poll_complete(&mut self.left_sink, RouterSinkError::Left),
-               poll_complete(&mut self.right_sink, RouterSinkError::Right)) {
-            (Ok(Async::Ready(())), Ok(Async::Ready(()))) => Ok(Async::Ready(())),
-            (Err(e), _) | (_, Err(e)) => Err(e),
-            (Ok(Async::NotReady), _) |
-            (_, Ok(Async::NotReady)) => Ok(Async::NotReady),
-        }
+        let first = self.flush_first();
+        self.poll_both(first, A::poll_complete, B::poll_complete)
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        let first = self.flush_first();
+        self.poll_both(first, A::close, B::close)
+    }
+}
+
+impl<S> From<[S; 2]> for RouterSink<S, S> {
+    /// Create a new RouterSink from two sinks of the same type, using the
+    /// first as the left and the second as the right route sink
+    fn from(sinks: [S; 2]) -> RouterSink<S, S> {
+        let [left_sink, right_sink] = sinks;
+        RouterSink::new(left_sink, right_sink)
     }
 }
 
@@ -187,6 +1191,11 @@ where A: Clone,
       B: Clone
 {
     fn clone(&self) -> Self {
-        RouterSink::new(self.left_sink.clone(), self.right_sink.clone())
+        let mut router = RouterSink::new(self.left_sink.clone(), self.right_sink.clone());
+        router.set_flush_bias(self.bias);
+        router.set_count_point(self.count_on);
+        router.left_sealed = self.left_sealed;
+        router.right_sealed = self.right_sealed;
+        router
     }
 }
\ No newline at end of file
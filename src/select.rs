@@ -0,0 +1,54 @@
+use error::RouterSinkError;
+use futures::{Future, Sink, Stream};
+use router_sink::{Route, RouterSink};
+
+/// Merge a stream of left items and a stream of right items into a router,
+/// tagging each item with the route of the stream it came from
+///
+/// Items are pulled from both streams alternately, as far as they are
+/// ready. Once one stream ends, the other one is forwarded on its own.
+///
+/// # Example
+///
+/// ```
+/// # extern crate futures;
+/// # extern crate futures_router_sink;
+/// # fn main() {
+/// use futures::{stream, Future};
+/// use futures_router_sink::{route_select, RouterSink, RouterSinkError};
+///
+/// let router = RouterSink::new(Vec::<usize>::new(), Vec::<&str>::new());
+/// let left = stream::iter_ok::<_, RouterSinkError<(), ()>>(vec![1, 2]);
+/// let right = stream::iter_ok(vec!["a"]);
+///
+/// let router = route_select(left, right, router).wait().unwrap();
+/// assert_eq!(router.left(), &vec![1, 2]);
+/// assert_eq!(router.right(), &vec!["a"]);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `left_stream`: The items routed to the left sink
+/// - `right_stream`: The items routed to the right sink
+/// - `router`: The router the items are forwarded into
+///
+/// # Return value
+///
+/// A future resolving to the router once both streams ended and the router
+/// has been flushed
+pub fn route_select<SL, SR, A, B>(left_stream: SL,
+                                  right_stream: SR,
+                                  router: RouterSink<A, B>)
+                                  -> impl Future<Item = RouterSink<A, B>, Error = SL::Error>
+    where SL: Stream<Item = A::SinkItem>,
+          SR: Stream<Item = B::SinkItem, Error = SL::Error>,
+          SL::Error: From<RouterSinkError<A::SinkError, B::SinkError>>,
+          A: Sink,
+          B: Sink
+{
+    left_stream.map(Route::Left)
+        .select(right_stream.map(Route::Right))
+        .forward(router)
+        .map(|(_, router)| router)
+}
@@ -0,0 +1,101 @@
+use futures::{Async, AsyncSink, Future, Poll, Sink};
+use router_sink::{Route, RouterSink};
+
+/// Future sending all items of an iterator into a sink, resolving to the
+/// sink once all items have been sent and flushed
+///
+/// Created by `RouterSink::send_all_routes`.
+#[must_use = "futures do nothing unless polled"]
+pub struct SendAll<S, I>
+    where S: Sink
+{
+    /// The sink to send into, taken once the future resolves
+    sink: Option<S>,
+    /// The remaining items
+    iter: I,
+    /// An item the sink was not ready to accept yet
+    buffered: Option<S::SinkItem>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink
+{
+    /// Send all routed items of an iterator, returning a future that
+    /// resolves to the router once all items have been sent and flushed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Future;
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let router = RouterSink::new(Vec::<usize>::new(), Vec::<usize>::new());
+    /// let items = vec![Route::Left(1), Route::Right(2), Route::Left(3)];
+    ///
+    /// let router = router.send_all_routes(items).wait().unwrap();
+    ///
+    /// assert_eq!(router.left(), &vec![1, 3]);
+    /// assert_eq!(router.right(), &vec![2]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `iter`: The items to send, tagged with the route they have to take
+    ///
+    /// # Return value
+    ///
+    /// A future resolving to the router after all items have been flushed
+    pub fn send_all_routes<I>(self, iter: I) -> SendAll<Self, I::IntoIter>
+        where I: IntoIterator<Item = Route<A::SinkItem, B::SinkItem>>
+    {
+        SendAll {
+            sink: Some(self),
+            iter: iter.into_iter(),
+            buffered: None,
+        }
+    }
+}
+
+impl<S, I> SendAll<S, I>
+    where S: Sink
+{
+    /// The sink to send into
+    fn sink_mut(&mut self) -> &mut S {
+        self.sink.as_mut().expect("polled SendAll after completion")
+    }
+
+    /// Try to send the given item, buffering it if the sink is not ready
+    fn try_start_send(&mut self, item: S::SinkItem) -> Poll<(), S::SinkError> {
+        if let AsyncSink::NotReady(item) = self.sink_mut().start_send(item)? {
+            self.buffered = Some(item);
+            return Ok(Async::NotReady);
+        }
+        Ok(Async::Ready(()))
+    }
+}
+
+impl<S, I> Future for SendAll<S, I>
+    where S: Sink,
+          I: Iterator<Item = S::SinkItem>
+{
+    type Item = S;
+    type Error = S::SinkError;
+
+    fn poll(&mut self) -> Poll<S, S::SinkError> {
+        if let Some(item) = self.buffered.take() {
+            try_ready!(self.try_start_send(item));
+        }
+
+        while let Some(item) = self.iter.next() {
+            try_ready!(self.try_start_send(item));
+        }
+
+        try_ready!(self.sink_mut().poll_complete());
+        Ok(Async::Ready(self.sink.take().expect("polled SendAll after completion")))
+    }
+}
@@ -0,0 +1,122 @@
+use buffered::BufferedRouter;
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::marker::PhantomData;
+
+/// A router stamping every item with a sequence number before it is sent,
+/// so the global order across both sides can be reassembled downstream
+///
+/// Sequence numbers start at zero and increase by one per item, regardless
+/// of its side. A number is only assigned once an item is accepted: a
+/// stamped item its sink is not ready for is held back and sent before
+/// the next item is stamped, so no number is skipped or assigned twice.
+pub struct SequencedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the stamped items are sent to
+    router: BufferedRouter<A, B>,
+    /// Stamps an item with its sequence number
+    with_seq: F,
+    /// The sequence number of the next item
+    next_seq: u64,
+    /// The item type accepted by the router
+    item: PhantomData<fn(T)>,
+}
+
+impl<A, B> RouterSink<A, B>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>
+{
+    /// Adapt the router to stamp every item with a sequence number, using
+    /// the given closure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::{Future, Sink};
+    /// use futures_router_sink::{Route, RouterSink};
+    ///
+    /// let router = RouterSink::new(Vec::<(u64, &str)>::new(), Vec::<(u64, &str)>::new())
+    ///     .sequenced(|seq, x| (seq, x));
+    ///
+    /// let router = router.send(Route::Right("a")).wait().unwrap();
+    /// let router = router.send(Route::Left("b")).wait().unwrap();
+    /// assert_eq!(router.get_ref().left(), &vec![(1, "b")]);
+    /// assert_eq!(router.get_ref().right(), &vec![(0, "a")]);
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `with_seq`: Stamps an item with its sequence number
+    ///
+    /// # Return value
+    ///
+    /// The router accepting unstamped items
+    pub fn sequenced<T, F>(self, with_seq: F) -> SequencedRouterSink<A, B, F, T>
+        where F: FnMut(u64, T) -> A::SinkItem
+    {
+        SequencedRouterSink {
+            router: BufferedRouter::new(self),
+            with_seq,
+            next_seq: 0,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<A, B, F, T> SequencedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.get_ref()
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.get_mut()
+    }
+}
+
+impl<A, B, F, T> Sink for SequencedRouterSink<A, B, F, T>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>,
+          F: FnMut(u64, T) -> A::SinkItem
+{
+    type SinkItem = Route<T, T>;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let with_seq = &mut self.with_seq;
+        let next_seq = &mut self.next_seq;
+        self.router.start_send_with(item, |item| {
+            let (side, item) = item.into_parts();
+            let seq = *next_seq;
+            *next_seq += 1;
+            Ok(Some(Route::from_parts(side, with_seq(seq, item))))
+        })
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,123 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+/// A sink routing items to one of two sinks based on the hash of the item
+///
+/// Items with an even hash are routed left, items with an odd hash are
+/// routed right. The shard assignment depends entirely on the hasher, so
+/// changing the hasher changes which side a given item ends up on.
+pub struct ShardedRouterSink<A, B, H = BuildHasherDefault<DefaultHasher>> {
+    /// The router the sharded items are sent to
+    router: RouterSink<A, B>,
+    /// The hasher used to compute the shard of an item
+    hasher: H,
+}
+
+impl<A, B> ShardedRouterSink<A, B> {
+    /// Create a new ShardedRouterSink for the two given sinks using the
+    /// default hasher
+    ///
+    /// The default hasher is unseeded, so the same item is routed to the
+    /// same side by every router in one build. Its algorithm may change
+    /// between Rust releases though, so the assignment must not be
+    /// persisted or relied on across builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::ShardedRouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = ShardedRouterSink::new(left, right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item has an even hash
+    /// - `right_sink`: The sink chosen by the router if an item has an odd hash
+    pub fn new(left_sink: A, right_sink: B) -> ShardedRouterSink<A, B> {
+        ShardedRouterSink::with_hasher(left_sink, right_sink, Default::default())
+    }
+}
+
+impl<A, B, H> ShardedRouterSink<A, B, H> {
+    /// Create a new ShardedRouterSink for the two given sinks using the
+    /// given hasher
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::RandomState;
+    /// use futures_router_sink::ShardedRouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = ShardedRouterSink::with_hasher(left, right, RandomState::new());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item has an even hash
+    /// - `right_sink`: The sink chosen by the router if an item has an odd hash
+    /// - `hasher`: The hasher used to compute the shard of an item
+    pub fn with_hasher(left_sink: A, right_sink: B, hasher: H) -> ShardedRouterSink<A, B, H> {
+        ShardedRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            hasher,
+        }
+    }
+
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, H> Sink for ShardedRouterSink<A, B, H>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>,
+          A::SinkItem: Hash,
+          H: BuildHasher
+{
+    type SinkItem = A::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let route = if self.hasher.hash_one(&item) & 1 == 0 {
+            Route::Left(item)
+        } else {
+            Route::Right(item)
+        };
+
+        self.router
+            .start_send(route)
+            .map(|x| x.map(Route::into_inner))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,99 @@
+use futures::{Poll, Sink, StartSend};
+use ready::PollReady;
+use router_sink::RouterSink;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A sink shared between clones, locking the inner sink per operation
+///
+/// Every `start_send`, `poll_complete` and `close` locks the inner sink
+/// for the duration of that call only, so clones can be used from
+/// different tasks or threads. The lock is blocking: a call waits while
+/// another clone is in the middle of an operation on the same sink.
+///
+/// Holding the guard returned by `lock` while sending through any clone on
+/// the same thread deadlocks. A panic while the inner sink is locked
+/// poisons it, after which every operation panics as well.
+pub struct SharedSink<S> {
+    /// The shared inner sink
+    inner: Arc<Mutex<S>>,
+}
+
+impl<S> SharedSink<S> {
+    /// Wrap the given sink so it can be shared between clones
+    ///
+    /// # Arguments
+    ///
+    /// - `sink`: The sink to share
+    pub fn new(sink: S) -> SharedSink<S> {
+        SharedSink { inner: Arc::new(Mutex::new(sink)) }
+    }
+
+    /// Lock the inner sink
+    ///
+    /// # Return value
+    ///
+    /// A guard giving access to the inner sink until it is dropped
+    pub fn lock(&self) -> MutexGuard<'_, S> {
+        self.inner.lock().expect("shared sink poisoned")
+    }
+}
+
+impl<S> Clone for SharedSink<S> {
+    fn clone(&self) -> Self {
+        SharedSink { inner: self.inner.clone() }
+    }
+}
+
+impl<S> Sink for SharedSink<S>
+    where S: Sink
+{
+    type SinkItem = S::SinkItem;
+    type SinkError = S::SinkError;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.lock().start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.lock().poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.lock().close()
+    }
+}
+
+impl<S> PollReady for SharedSink<S>
+    where S: PollReady
+{
+    fn poll_ready(&mut self) -> Poll<(), Self::SinkError> {
+        self.lock().poll_ready()
+    }
+}
+
+impl<A, B> RouterSink<SharedSink<A>, SharedSink<B>> {
+    /// Create a new RouterSink whose clones share the two given sinks,
+    /// instead of cloning them
+    ///
+    /// See `SharedSink` for the locking semantics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = RouterSink::shared(left, right);
+    /// let other = router.clone();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    pub fn shared(left_sink: A, right_sink: B) -> RouterSink<SharedSink<A>, SharedSink<B>> {
+        RouterSink::new(SharedSink::new(left_sink), SharedSink::new(right_sink))
+    }
+}
@@ -0,0 +1,133 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use futures::sink;
+use router_sink::{Route, RouterSink, Side};
+
+/// A sink routing payload-free signals to one of two sinks
+///
+/// Signals are routed by `Side` alone, so there is no need to construct a
+/// `Route` around the unit item.
+pub struct SignalRouterSink<A, B> {
+    /// The router the signals are sent to
+    router: RouterSink<A, B>,
+}
+
+impl<A, B> SignalRouterSink<A, B> {
+    /// Create a new SignalRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::SignalRouterSink;
+    ///
+    /// let left = Vec::<()>::new();
+    /// let right = Vec::<()>::new();
+    ///
+    /// let router = SignalRouterSink::new(left, right);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if a signal is sent `Left`
+    /// - `right_sink`: The sink chosen by the router if a signal is sent `Right`
+    pub fn new(left_sink: A, right_sink: B) -> SignalRouterSink<A, B> {
+        SignalRouterSink { router: RouterSink::new(left_sink, right_sink) }
+    }
+
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B> SignalRouterSink<A, B>
+    where A: Sink<SinkItem = ()>,
+          B: Sink<SinkItem = ()>
+{
+    /// Send a signal to the left sink, returning a future that resolves
+    /// to the router once the signal has been sent and flushed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Future;
+    /// use futures_router_sink::SignalRouterSink;
+    ///
+    /// let router = SignalRouterSink::new(Vec::<()>::new(), Vec::<()>::new());
+    /// let router = router.send_left().wait().unwrap();
+    ///
+    /// assert_eq!(router.get_ref().left().len(), 1);
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A future resolving to the router after the signal has been flushed
+    pub fn send_left(self) -> sink::Send<Self> {
+        self.send(Side::Left)
+    }
+
+    /// Send a signal to the right sink, returning a future that resolves
+    /// to the router once the signal has been sent and flushed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate futures;
+    /// # extern crate futures_router_sink;
+    /// # fn main() {
+    /// use futures::Future;
+    /// use futures_router_sink::SignalRouterSink;
+    ///
+    /// let router = SignalRouterSink::new(Vec::<()>::new(), Vec::<()>::new());
+    /// let router = router.send_right().wait().unwrap();
+    ///
+    /// assert_eq!(router.get_ref().right().len(), 1);
+    /// # }
+    /// ```
+    ///
+    /// # Return value
+    ///
+    /// A future resolving to the router after the signal has been flushed
+    pub fn send_right(self) -> sink::Send<Self> {
+        self.send(Side::Right)
+    }
+}
+
+impl<A, B> Sink for SignalRouterSink<A, B>
+    where A: Sink<SinkItem = ()>,
+          B: Sink<SinkItem = ()>
+{
+    type SinkItem = Side;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.router
+            .start_send(Route::from_side(item, ()))
+            .map(|x| x.map(|_| item))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,99 @@
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink};
+
+/// A sink routing items to one of two sinks by the size of the item,
+/// typically to tier storage into a fast and a bulk sink
+///
+/// Items whose size is at or below the threshold are routed to the fast
+/// left sink, all others are routed to the bulk right sink.
+pub struct SizeRouterSink<A, B, F> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// Computes the size of an item
+    size_fn: F,
+    /// The largest size still routed to the fast sink
+    threshold: usize,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new SizeRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::RouterSink;
+    ///
+    /// let fast = Vec::<Vec<u8>>::new();
+    /// let bulk = Vec::<Vec<u8>>::new();
+    ///
+    /// let router = RouterSink::by_size(fast, bulk, 4096, |x: &Vec<u8>| x.len());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `fast_sink`: The sink chosen by the router if the size is at or below the threshold
+    /// - `bulk_sink`: The sink chosen by the router if the size is above the threshold
+    /// - `threshold`: The largest size still routed to the fast sink
+    /// - `size_fn`: Computes the size of an item
+    pub fn by_size<F>(fast_sink: A,
+                      bulk_sink: B,
+                      threshold: usize,
+                      size_fn: F)
+                      -> SizeRouterSink<A, B, F> {
+        SizeRouterSink {
+            router: RouterSink::new(fast_sink, bulk_sink),
+            size_fn,
+            threshold,
+        }
+    }
+}
+
+impl<A, B, F> SizeRouterSink<A, B, F> {
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, F> Sink for SizeRouterSink<A, B, F>
+    where A: Sink,
+          B: Sink<SinkItem = A::SinkItem>,
+          F: Fn(&A::SinkItem) -> usize
+{
+    type SinkItem = A::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let route = if (self.size_fn)(&item) <= self.threshold {
+            Route::Left(item)
+        } else {
+            Route::Right(item)
+        };
+
+        self.router
+            .start_send(route)
+            .map(|x| x.map(Route::into_inner))
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}
@@ -0,0 +1,202 @@
+use error::RouterSinkError;
+use futures::{Async, Poll, Sink, StartSend};
+use router_sink::{RouterSink, Side};
+use std::time::{Duration, Instant};
+
+/// A source of time for `TimeoutRouterSink`, abstracting over the timer
+/// of the runtime in use
+pub trait Clock {
+    /// The current instant
+    fn now(&self) -> Instant;
+
+    /// Arrange for the current task to be notified at the given deadline
+    ///
+    /// The default implementation does nothing, in which case a timeout
+    /// is only detected once the stuck sink notifies the task itself.
+    /// Runtimes with a timer should override this so a stuck sink is
+    /// reported even if it never notifies the task again.
+    ///
+    /// # Arguments
+    ///
+    /// - `deadline`: The instant at which the current task is notified
+    fn notify_at(&self, deadline: Instant) {
+        let _ = deadline;
+    }
+}
+
+/// A clock reading the system's monotonic clock, without a timer
+///
+/// As it never arms a wakeup, a timeout measured with it is only detected
+/// when the sink is polled again for another reason.
+pub struct StdClock;
+
+impl Clock for StdClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A sink routing items to one of two sinks, giving up on a flush once a
+/// sink has not been ready for longer than the timeout
+///
+/// The timeout of a sink starts the first time flushing it returns
+/// `NotReady`, and is reset once flushing it returns `Ready`. Once a sink
+/// has been pending for the whole timeout, `poll_complete` fails with
+/// `RouterSinkError::FlushTimeout` for its side. The same applies to
+/// `close`, which also closes the inner sinks.
+///
+/// The timeout is only checked when the router is polled. With a clock
+/// that arms a wakeup in `Clock::notify_at`, the task is polled once the
+/// timeout expires. With `StdClock`, which arms none, a sink that returns
+/// `NotReady` and never notifies the task again is only reported once
+/// something else polls the router, e.g. another item being sent or a
+/// periodic flush.
+pub struct TimeoutRouterSink<A, B, C> {
+    /// The router the items are sent to
+    router: RouterSink<A, B>,
+    /// How long a sink may stay pending
+    timeout: Duration,
+    /// The source of time
+    clock: C,
+    /// Since when the left sink is pending
+    left_pending_since: Option<Instant>,
+    /// Since when the right sink is pending
+    right_pending_since: Option<Instant>,
+}
+
+impl<A, B, C> TimeoutRouterSink<A, B, C> {
+    /// Create a new TimeoutRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use futures_router_sink::{StdClock, TimeoutRouterSink};
+    ///
+    /// let left = Vec::<usize>::new();
+    /// let right = Vec::<usize>::new();
+    ///
+    /// let router = TimeoutRouterSink::new(left, right, Duration::from_secs(1), StdClock);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is tagged as `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is tagged as `Right`
+    /// - `timeout`: How long a sink may stay pending
+    /// - `clock`: The source of time
+    pub fn new(left_sink: A,
+               right_sink: B,
+               timeout: Duration,
+               clock: C)
+               -> TimeoutRouterSink<A, B, C> {
+        TimeoutRouterSink {
+            router: RouterSink::new(left_sink, right_sink),
+            timeout,
+            clock,
+            left_pending_since: None,
+            right_pending_since: None,
+        }
+    }
+
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        &self.router
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        &mut self.router
+    }
+}
+
+impl<A, B, C> TimeoutRouterSink<A, B, C>
+    where C: Clock
+{
+    /// Update when the sink for the given side became pending, given
+    /// whether it is pending now
+    ///
+    /// # Return value
+    ///
+    /// Whether the sink for the given side timed out
+    fn timed_out(&mut self, side: Side, pending: bool) -> bool {
+        let since = match side {
+            Side::Left => &mut self.left_pending_since,
+            Side::Right => &mut self.right_pending_since,
+        };
+
+        if !pending {
+            *since = None;
+            return false;
+        }
+
+        let now = self.clock.now();
+        let deadline = *since.get_or_insert(now) + self.timeout;
+        if now >= deadline {
+            *since = None;
+            return true;
+        }
+        self.clock.notify_at(deadline);
+        false
+    }
+}
+
+impl<A, B, C> TimeoutRouterSink<A, B, C>
+    where A: Sink,
+          B: Sink,
+          C: Clock
+{
+    /// Combine the results of flushing or closing both sinks, failing for a
+    /// side that has been pending for the whole timeout
+    fn expire(&mut self,
+              left: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>,
+              right: Poll<(), RouterSinkError<A::SinkError, B::SinkError>>)
+              -> Poll<(), RouterSinkError<A::SinkError, B::SinkError>> {
+        let left = left?;
+        let right = right?;
+
+        if self.timed_out(Side::Left, left.is_not_ready()) {
+            return Err(RouterSinkError::FlushTimeout(Side::Left));
+        }
+        if self.timed_out(Side::Right, right.is_not_ready()) {
+            return Err(RouterSinkError::FlushTimeout(Side::Right));
+        }
+
+        if left.is_ready() && right.is_ready() {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl<A, B, C> Sink for TimeoutRouterSink<A, B, C>
+    where A: Sink,
+          B: Sink,
+          C: Clock
+{
+    type SinkItem = <RouterSink<A, B> as Sink>::SinkItem;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.router.start_send(item)
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        let (left, right) = self.router.poll_complete_each();
+        self.expire(left, right)
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        let (left, right) = self.router.close_each();
+        self.expire(left, right)
+    }
+}
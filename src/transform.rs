@@ -0,0 +1,133 @@
+use buffered::BufferedRouter;
+use error::RouterSinkError;
+use futures::{Poll, Sink, StartSend};
+use router_sink::{Route, RouterSink, Side};
+use std::marker::PhantomData;
+
+/// A sink routing a single item type to one of two sinks, transforming
+/// the item into the item type of the chosen sink
+///
+/// The item is routed by reference first, so only the chosen side's
+/// transform runs on it. A transformed item its sink is not ready for is
+/// held back and sent before the next item, so no item is transformed
+/// twice.
+pub struct TransformRouterSink<A, B, FL, FR, R, T>
+    where A: Sink,
+          B: Sink
+{
+    /// The router the transformed items are sent to
+    router: BufferedRouter<A, B>,
+    /// Transforms an item routed left
+    left_fn: FL,
+    /// Transforms an item routed right
+    right_fn: FR,
+    /// Decides which route an item has to take
+    router_fn: R,
+    /// The item type accepted by the router
+    item: PhantomData<fn(T)>,
+}
+
+impl<A, B> RouterSink<A, B> {
+    /// Create a new TransformRouterSink for the two given sinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_router_sink::{RouterSink, Side};
+    ///
+    /// let text = Vec::<String>::new();
+    /// let binary = Vec::<Vec<u8>>::new();
+    ///
+    /// let router = RouterSink::with_transforms(text,
+    ///                                          binary,
+    ///                                          |x: &str| x.to_string(),
+    ///                                          |x: &str| x.as_bytes().to_vec(),
+    ///                                          |x: &&str| if x.is_ascii() {
+    ///                                              Side::Left
+    ///                                          } else {
+    ///                                              Side::Right
+    ///                                          });
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `left_sink`: The sink chosen by the router if an item is routed `Left`
+    /// - `right_sink`: The sink chosen by the router if an item is routed `Right`
+    /// - `left_fn`: Transforms an item routed `Left` into the left sink's item
+    /// - `right_fn`: Transforms an item routed `Right` into the right sink's item
+    /// - `router_fn`: Decides which route an item has to take
+    pub fn with_transforms<FL, FR, R, T>(left_sink: A,
+                                         right_sink: B,
+                                         left_fn: FL,
+                                         right_fn: FR,
+                                         router_fn: R)
+                                         -> TransformRouterSink<A, B, FL, FR, R, T>
+        where A: Sink,
+              B: Sink,
+              FL: FnMut(T) -> A::SinkItem,
+              FR: FnMut(T) -> B::SinkItem,
+              R: FnMut(&T) -> Side
+    {
+        TransformRouterSink {
+            router: BufferedRouter::new(RouterSink::new(left_sink, right_sink)),
+            left_fn,
+            right_fn,
+            router_fn,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<A, B, FL, FR, R, T> TransformRouterSink<A, B, FL, FR, R, T>
+    where A: Sink,
+          B: Sink
+{
+    /// Access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A reference to the inner router
+    pub fn get_ref(&self) -> &RouterSink<A, B> {
+        self.router.get_ref()
+    }
+
+    /// Mutable access the inner router
+    ///
+    /// # Return value
+    ///
+    /// A mutable reference to the inner router
+    pub fn get_mut(&mut self) -> &mut RouterSink<A, B> {
+        self.router.get_mut()
+    }
+}
+
+impl<A, B, FL, FR, R, T> Sink for TransformRouterSink<A, B, FL, FR, R, T>
+    where A: Sink,
+          B: Sink,
+          FL: FnMut(T) -> A::SinkItem,
+          FR: FnMut(T) -> B::SinkItem,
+          R: FnMut(&T) -> Side
+{
+    type SinkItem = T;
+    type SinkError = RouterSinkError<A::SinkError, B::SinkError>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        let left_fn = &mut self.left_fn;
+        let right_fn = &mut self.right_fn;
+        let router_fn = &mut self.router_fn;
+        self.router.start_send_with(item, |item| {
+            Ok(Some(match router_fn(&item) {
+                Side::Left => Route::Left(left_fn(item)),
+                Side::Right => Route::Right(right_fn(item)),
+            }))
+        })
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.poll_complete()
+    }
+
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.router.close()
+    }
+}